@@ -2,10 +2,11 @@ use std::fmt::Write;
 use std::path::Path;
 use std::{fs, io};
 
-use cocogitto::COMMITS_METADATA;
+use cocogitto::settings::ScopePattern;
+use cocogitto::{COMMITS_METADATA, SETTINGS};
 
 use anyhow::{bail, Result};
-use clap::builder::PossibleValuesParser;
+use clap::builder::{PossibleValue, PossibleValuesParser, TypedValueParser};
 use conventional_commit_parser::commit::Separator;
 use itertools::Itertools;
 
@@ -18,14 +19,70 @@ pub fn commit_types() -> PossibleValuesParser {
     types.into()
 }
 
+/// Accepts any scope, validation is done against the configured patterns once the
+/// commit type is known. Literal scopes are exposed as possible values for shell completions.
+#[derive(Clone)]
+pub struct ScopeValueParser;
+
+impl TypedValueParser for ScopeValueParser {
+    type Value = String;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        clap::builder::StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        let type_scopes = SETTINGS
+            .commit_types
+            .values()
+            .filter_map(|config| config.scopes.as_ref())
+            .flatten()
+            .map(ScopePattern::as_str);
+
+        let scopes = SETTINGS
+            .scopes
+            .iter()
+            .flatten()
+            .map(ScopePattern::as_str)
+            .chain(type_scopes)
+            .chain(
+                SETTINGS
+                    .monorepo
+                    .as_ref()
+                    .filter(|monorepo| monorepo.package_scopes)
+                    .map(|monorepo| monorepo.packages.keys())
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str),
+            )
+            .filter(|scope| !is_scope_pattern(scope))
+            .unique()
+            .sorted()
+            .map(PossibleValue::new);
+
+        Some(Box::new(scopes))
+    }
+}
+
+pub fn is_scope_pattern(scope: &str) -> bool {
+    scope.starts_with('/') || scope.contains(['*', '?', '[', '{'])
+}
+
 pub fn prepare_edit_message<P: AsRef<Path>>(
     typ: &str,
     message: &str,
     scope: Option<&str>,
+    body: Option<&str>,
+    footer: Option<&str>,
     breaking: bool,
     path: P,
 ) -> io::Result<String> {
-    let template = prepare_edit_template(typ, message, scope, breaking);
+    let template = prepare_edit_template(typ, message, scope, body, footer, breaking);
     fs::write(path, &template)?;
     Ok(template)
 }
@@ -95,9 +152,18 @@ fn prepare_header(typ: &str, message: &str, scope: Option<&str>) -> String {
     header
 }
 
-fn prepare_edit_template(typ: &str, message: &str, scope: Option<&str>, breaking: bool) -> String {
+fn prepare_edit_template(
+    typ: &str,
+    message: &str,
+    scope: Option<&str>,
+    body: Option<&str>,
+    footer: Option<&str>,
+    breaking: bool,
+) -> String {
     let mut template: String = EDIT_TEMPLATE.into();
     let header = prepare_header(typ, message, scope);
+    let body = body.unwrap_or_default();
+    let footer = footer.unwrap_or_default();
 
     if breaking {
         template.push_str("# WARNING: This will be marked as a breaking change!\n");
@@ -105,7 +171,7 @@ fn prepare_edit_template(typ: &str, message: &str, scope: Option<&str>, breaking
 
     write!(
         &mut template,
-        "{header}\n\n# Message body\n\n\n# Message footer\n# For example, foo: bar\n\n\n"
+        "{header}\n\n# Message body\n{body}\n\n# Message footer\n# For example, foo: bar\n{footer}\n\n"
     )
     .unwrap();
 
@@ -1,15 +1,18 @@
 mod commit;
 mod mangen;
+mod wizard;
 
 use std::fs;
 use std::io::{self, Read};
 use std::path::PathBuf;
 
 use cocogitto::conventional::changelog::context::RemoteContext;
+use cocogitto::conventional::changelog::export::ChangelogFormat;
 use cocogitto::conventional::changelog::template::Template;
 use cocogitto::conventional::changelog::ReleaseType;
 use cocogitto::conventional::commit as conv_commit;
 use cocogitto::conventional::version::{IncrementCommand, PreCommand};
+use cocogitto::error::{CogCheckReport, ReportFormat};
 
 use cocogitto::log::filter::{CommitFilter, CommitFilters};
 use cocogitto::log::output::Output;
@@ -21,7 +24,7 @@ use clap::builder::{PossibleValue, PossibleValuesParser};
 use clap::{ArgAction, ArgGroup, Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{shells, Generator};
 use clap_complete_nushell::Nushell;
-use cocogitto::command::bump::{BumpOptions, PackageBumpOptions};
+use cocogitto::command::bump::{BumpOptions, BumpPlanFormat, PackageBumpOptions};
 use cocogitto::command::commit::CommitOptions;
 use cocogitto::settings::GitHookType;
 
@@ -165,9 +168,21 @@ enum Command {
         /// Check commits in the specified range
         #[arg(group = "commit_range")]
         range: Option<String>,
+
+        /// Output format of the report
+        #[arg(long, value_enum, default_value_t)]
+        format: ReportFormat,
+    },
+
+    /// Verify a branch name against the configured branch patterns
+    CheckBranch {
+        /// Branch name to check, defaults to the current branch
+        branch: Option<String>,
     },
 
     /// Create a new conventional commit
+    ///
+    /// Run without arguments to use the interactive wizard
     Commit(CommitArgs),
 
     /// Interactively rename invalid commit messages
@@ -218,6 +233,24 @@ enum Command {
         /// Ignore fixup!, squash! and amend! commit messages
         #[arg(long)]
         ignore_fixup_commits: bool,
+
+        /// Output format of the report
+        #[arg(long, value_enum, default_value_t)]
+        format: ReportFormat,
+    },
+
+    /// Write a conventional commit template to a commit message file, inferring type and scope
+    /// from the branch name and staged files. Meant to be used as a `prepare-commit-msg` git hook
+    PrepareMessage {
+        /// Path to the commit message file
+        file: PathBuf,
+
+        /// Source of the commit message, as given by git
+        source: Option<String>,
+
+        /// Commit object name, as given by git (unused)
+        #[arg(hide = true)]
+        commit: Option<String>,
     },
 
     /// Display a changelog for the given commit oid range
@@ -232,7 +265,7 @@ enum Command {
 
         /// Generate the changelog with the given template.
         ///
-        /// Possible values are 'remote', 'full_hash', 'default' or the path to your template.
+        /// Possible values are 'remote', 'full_hash', 'default', 'keepachangelog', 'asciidoc' or the path to your template.
         /// If not specified cog will use cog.toml template config or fallback to 'default'.
         #[arg(long, short)]
         template: Option<String>,
@@ -252,6 +285,10 @@ enum Command {
         /// Combine package and global changes into one changelog
         #[arg(short, long)]
         unified: bool,
+
+        /// Output format, 'json' and 'yaml' export the releases instead of rendering a template
+        #[arg(long, value_enum, default_value_t, conflicts_with = "template")]
+        format: ChangelogFormat,
     },
 
     /// Get current version
@@ -275,6 +312,7 @@ enum Command {
 
     /// Commit changelog from latest tag to HEAD and create new tag
     #[command(group = ArgGroup::new("bump-spec").required(true))]
+    #[command(group = ArgGroup::new("plan").multiple(true))]
     Bump {
         /// Manually set the target version
         #[arg(long, group = "bump-spec")]
@@ -296,6 +334,14 @@ enum Command {
         #[arg(short, long, group = "bump-spec")]
         patch: bool,
 
+        /// Release 1.0.0, ending the initial development of a 0.y.z version
+        #[arg(long, group = "bump-spec")]
+        graduate: bool,
+
+        /// Release the latest pre-release as a stable version, with a changelog covering all the pre-releases
+        #[arg(long, group = "bump-spec", conflicts_with_all = ["pre", "include_packages"])]
+        promote: bool,
+
         /// Set the pre-release version (e.g. "beta.1")
         ///
         /// Or increment based on a pattern (e.g. "beta.*")
@@ -321,9 +367,14 @@ enum Command {
         annotated: Option<String>,
 
         /// Dry-run: print the target version. No action taken
-        #[arg(short, long)]
+        #[arg(short, long, group = "plan")]
         dry_run: bool,
 
+        /// Print the bump plan with the increment of every commit and how the next version
+        /// is decided. No action taken
+        #[arg(long, group = "plan")]
+        explain: bool,
+
         /// Add the skip-ci string defined in the cog.toml (or defaults to [skip ci]) to the bump commit
         #[arg(long = "skip-ci")]
         skip_ci: bool,
@@ -340,12 +391,20 @@ enum Command {
         #[arg(long = "disable-bump-commit")]
         disable_bump_commit: bool,
 
+        /// Keep the bump commit and tags when a later step fails, instead of rolling back
+        #[arg(long = "no-rollback")]
+        no_rollback: bool,
+
         /// Also bump packages on manual bump
         ///
         /// Overrides the default behaviour for --patch, --minor, --major and --version to only
         /// bump the global version for monorepos. Useful to bump to version 1.0.0.
         #[arg(long, conflicts_with = "auto")]
         include_packages: bool,
+
+        /// Output format of the dry-run, 'text' and 'json' print the full bump plan
+        #[arg(long, value_enum, default_value_t, requires = "plan")]
+        format: BumpPlanFormat,
     },
 
     /// Install cog config files
@@ -384,12 +443,13 @@ enum Command {
 struct CommitArgs {
     /// Conventional commit type
     #[arg(name = "type", value_name = "TYPE", value_parser = commit::commit_types())]
-    typ: String,
+    typ: Option<String>,
 
     /// Commit description
-    message: String,
+    message: Option<String>,
 
     /// Conventional commit scope
+    #[arg(value_parser = commit::ScopeValueParser)]
     scope: Option<String>,
 
     /// Create a BREAKING CHANGE commit
@@ -463,6 +523,8 @@ fn main() -> Result<()> {
             major,
             minor,
             patch,
+            graduate,
+            promote,
             pre,
             build,
             hook_profile,
@@ -473,9 +535,19 @@ fn main() -> Result<()> {
             skip_ci_override,
             skip_untracked,
             disable_bump_commit,
+            no_rollback,
             include_packages,
+            format,
+            explain,
         } => {
             let mut cocogitto = CocoGitto::get()?;
+            // Explaining a bump is a dry-run printing the full bump plan
+            let dry_run = dry_run || explain;
+            let format = match format {
+                BumpPlanFormat::Version if explain => BumpPlanFormat::Text,
+                format => format,
+            };
+
             let is_monorepo = SETTINGS
                 .monorepo
                 .as_ref()
@@ -497,6 +569,8 @@ fn main() -> Result<()> {
                 None if major => IncrementCommand::Major,
                 None if minor => IncrementCommand::Minor,
                 None if patch => IncrementCommand::Patch,
+                None if graduate => IncrementCommand::Graduate,
+                None if promote => IncrementCommand::Promote,
                 _ => unreachable!(),
             };
 
@@ -533,6 +607,9 @@ fn main() -> Result<()> {
                             skip_ci_override,
                             skip_untracked,
                             disable_bump_commit,
+                            no_rollback,
+                            format,
+                            explain,
                         };
 
                         cocogitto.create_package_version(opts)?
@@ -549,7 +626,10 @@ fn main() -> Result<()> {
                             skip_ci_override,
                             skip_untracked,
                             disable_bump_commit,
+                            no_rollback,
                             include_packages,
+                            format,
+                            explain,
                         };
 
                         cocogitto.create_monorepo_version(opts)?
@@ -567,16 +647,24 @@ fn main() -> Result<()> {
                     skip_ci_override,
                     skip_untracked,
                     disable_bump_commit,
+                    no_rollback,
                     include_packages,
+                    format,
+                    explain,
                 };
                 cocogitto.create_version(opts)?
             }
         }
+        Command::PrepareMessage { file, source, .. } => {
+            let cocogitto = CocoGitto::get()?;
+            cocogitto.prepare_message(&file, source.as_deref())?;
+        }
         Command::Verify {
             message,
             file,
             ignore_merge_commits,
             ignore_fixup_commits,
+            format,
         } => {
             let ignore_merge_commits = ignore_merge_commits || SETTINGS.ignore_merge_commits;
             let ignore_fixup_commits = ignore_fixup_commits || SETTINGS.ignore_fixup_commits;
@@ -610,29 +698,56 @@ fn main() -> Result<()> {
                 (Some(_), Some(_)) => unreachable!(),
             };
 
-            conv_commit::verify(
+            let outcome = conv_commit::verify(
                 author,
                 &commit_message,
                 ignore_merge_commits,
                 ignore_fixup_commits,
-            )?;
+            );
+
+            match format {
+                ReportFormat::Text => outcome?,
+                format => {
+                    let report = CogCheckReport {
+                        from: None,
+                        errors: outcome.err().map(|err| vec![*err]).unwrap_or_default(),
+                    };
+                    print_report(&report, format)?;
+                }
+            }
         }
         Command::Check {
             from_latest_tag,
             ignore_merge_commits,
             ignore_fixup_commits,
             range,
+            format,
         } => {
             let cocogitto = CocoGitto::get()?;
             let from_latest_tag = from_latest_tag || SETTINGS.from_latest_tag;
             let ignore_merge_commits = ignore_merge_commits || SETTINGS.ignore_merge_commits;
             let ignore_fixup_commits = ignore_fixup_commits || SETTINGS.ignore_fixup_commits;
-            cocogitto.check(
-                from_latest_tag,
-                ignore_merge_commits,
-                ignore_fixup_commits,
-                range,
-            )?;
+            match format {
+                ReportFormat::Text => cocogitto.check(
+                    from_latest_tag,
+                    ignore_merge_commits,
+                    ignore_fixup_commits,
+                    range,
+                )?,
+                format => {
+                    let report = cocogitto.check_report(
+                        from_latest_tag,
+                        ignore_merge_commits,
+                        ignore_fixup_commits,
+                        range,
+                    )?;
+                    print_report(&report, format)?;
+                }
+            }
+        }
+        Command::CheckBranch { branch } => {
+            let cocogitto = CocoGitto::get()?;
+            cocogitto.check_branch(branch)?;
         }
         Command::Edit { from_latest_tag } => {
             let cocogitto = CocoGitto::get()?;
@@ -697,8 +812,30 @@ fn main() -> Result<()> {
             owner,
             repository,
             unified,
+            format,
         } => {
             let cocogitto = CocoGitto::get()?;
+            // TODO: fallback to tag here
+            let pattern = pattern.as_deref().unwrap_or("..");
+            let is_monorepo = SETTINGS
+                .monorepo
+                .as_ref()
+                .map(|m| !m.packages.is_empty())
+                .unwrap_or(false);
+
+            if format != ChangelogFormat::Markdown {
+                let release = match at.as_deref() {
+                    Some(at) => cocogitto.get_changelog(at, false)?,
+                    None if is_monorepo => cocogitto.get_monorepo_release(pattern, unified)?,
+                    None => cocogitto.get_changelog(pattern, true)?,
+                };
+                let output = match format {
+                    ChangelogFormat::Yaml => release.to_yaml()?,
+                    _ => release.to_json()?,
+                };
+                println!("{output}");
+                return Ok(());
+            }
 
             let context = RemoteContext::try_new(remote, repository, owner)
                 .or_else(|| SETTINGS.get_template_context());
@@ -709,22 +846,14 @@ fn main() -> Result<()> {
                 Template::fallback(unified)
             };
 
-            // TODO: fallback to tag here
-            let pattern = pattern.as_deref().unwrap_or("..");
             let result = match at {
                 Some(at) => cocogitto.get_changelog_at_tag(&at, template)?,
+                None if is_monorepo => {
+                    cocogitto.get_monorepo_changelog(pattern, template, unified)?
+                }
                 None => {
-                    if SETTINGS
-                        .monorepo
-                        .as_ref()
-                        .map(|m| !m.packages.is_empty())
-                        .unwrap_or(false)
-                    {
-                        cocogitto.get_monorepo_changelog(pattern, template, unified)?
-                    } else {
-                        let changelog = cocogitto.get_changelog(pattern, true)?;
-                        changelog.into_markdown(template, ReleaseType::Standard)?
-                    }
+                    let changelog = cocogitto.get_changelog(pattern, true)?;
+                    changelog.into_markdown(template, ReleaseType::Standard)?
                 }
             };
             println!("{result}");
@@ -769,6 +898,24 @@ fn main() -> Result<()> {
             cocogitto.run_commit_hook(CommitHook::PreCommit)?;
             let commit_message_path = cocogitto.prepare_edit_message_path();
 
+            let (typ, message, scope, wizard_body, wizard_footer, breaking_change) =
+                match (typ, message) {
+                    (Some(typ), Some(message)) => {
+                        (typ, message, scope, None, None, breaking_change)
+                    }
+                    (typ, _) => {
+                        let answers = wizard::run(&cocogitto, typ.as_deref())?;
+                        (
+                            answers.commit_type,
+                            answers.summary,
+                            answers.scope,
+                            answers.body,
+                            answers.footer,
+                            answers.breaking || breaking_change,
+                        )
+                    }
+                };
+
             let commit_message = if skip_ci || skip_ci_override.is_some() {
                 format!(
                     "{} {}",
@@ -783,6 +930,8 @@ fn main() -> Result<()> {
                 &typ,
                 &commit_message,
                 scope.as_deref(),
+                wizard_body.as_deref(),
+                wizard_footer.as_deref(),
                 breaking_change,
                 &commit_message_path,
             )?;
@@ -791,7 +940,7 @@ fn main() -> Result<()> {
             let (body, footer, breaking) = if edit {
                 commit::edit_message(&commit_message_path, breaking_change)?
             } else {
-                (None, None, breaking_change)
+                (wizard_body, wizard_footer, breaking_change)
             };
 
             let opts = CommitOptions {
@@ -815,6 +964,24 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Print a machine-readable report on stdout, failing with the same exit code
+/// as the text output when the report contains errors.
+fn print_report(report: &CogCheckReport, format: ReportFormat) -> Result<()> {
+    let output = match format {
+        ReportFormat::Json => report.to_json()?,
+        ReportFormat::Sarif => report.to_sarif()?,
+        ReportFormat::Text => report.to_string(),
+    };
+
+    println!("{output}");
+
+    if !report.is_empty() {
+        bail!("Found {} non compliant commits", report.errors.len());
+    }
+
+    Ok(())
+}
+
 fn init_logs(verbose: u8, quiet: bool) {
     let verbosity = if verbose == 0 { 2 } else { verbose - 1 };
     stderrlog::new()
@@ -0,0 +1,285 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::IsTerminal;
+
+use crate::commit::is_scope_pattern;
+use anyhow::{bail, Result};
+use cocogitto::conventional::commit::{verify, CommitConfig};
+use cocogitto::{CocoGitto, COMMITS_METADATA, SETTINGS};
+use conventional_commit_parser::commit::CommitType;
+use inquire::validator::{StringValidator, Validation};
+use inquire::{Confirm, Select, Text};
+use itertools::Itertools;
+
+/// Answers collected by the interactive commit wizard
+pub struct WizardAnswers {
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub summary: String,
+    pub body: Option<String>,
+    pub footer: Option<String>,
+    pub breaking: bool,
+}
+
+impl WizardAnswers {
+    /// Render the conventional commit message for these answers and check it against
+    /// the configuration, as `cog verify` would.
+    fn message(&self, author: Option<String>) -> Result<String> {
+        let message = CocoGitto::get_conventional_message(
+            &self.commit_type,
+            self.scope.clone(),
+            self.summary.clone(),
+            self.body.clone(),
+            self.footer.clone(),
+            self.breaking,
+        )?;
+
+        verify(author, &message, false, false)?;
+        Ok(message)
+    }
+}
+
+struct TypeChoice<'a> {
+    commit_type: &'a CommitType,
+    config: &'a CommitConfig,
+}
+
+impl Display for TypeChoice<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let title = self.config.changelog_title.as_deref().unwrap_or_default();
+        write!(f, "{:<10} {}", self.commit_type.as_ref(), title)
+    }
+}
+
+const NO_SCOPE: &str = "(no scope)";
+const CUSTOM_SCOPE: &str = "(other)";
+
+#[derive(Clone)]
+struct NotEmpty;
+
+impl StringValidator for NotEmpty {
+    fn validate(&self, input: &str) -> Result<Validation, inquire::CustomUserError> {
+        if input.trim().is_empty() {
+            Ok(Validation::Invalid("This field is required".into()))
+        } else {
+            Ok(Validation::Valid)
+        }
+    }
+}
+
+/// Prompt the user for every part of a conventional commit, preview and validate
+/// the resulting message. The type selection is skipped if `commit_type` is given.
+pub fn run(cocogitto: &CocoGitto, commit_type: Option<&str>) -> Result<WizardAnswers> {
+    if !std::io::stdin().is_terminal() {
+        let missing = if commit_type.is_some() {
+            "message"
+        } else {
+            "type and message"
+        };
+        bail!("Missing commit {missing}: run `cog commit` in a terminal to use the interactive wizard, or provide `<TYPE> <MESSAGE>`");
+    }
+
+    // Parts of the current branch name matching `branch_patterns` are used as defaults
+    let branch_name = cocogitto.current_branch_name().unwrap_or_default();
+
+    let commit_type = match commit_type {
+        Some(commit_type) => CommitType::from(commit_type),
+        None => prompt_type(branch_name.commit_type.as_ref())?,
+    };
+
+    let scope = prompt_scope(&commit_type, branch_name.scope.as_deref())?;
+
+    let summary = Text::new("Write a short summary:")
+        .with_validator(NotEmpty)
+        .prompt()?;
+
+    let body = Text::new("Provide a longer description (optional):").prompt()?;
+    let body = Some(body.trim().to_string()).filter(|body| !body.is_empty());
+
+    let breaking = Confirm::new("Is this a breaking change?")
+        .with_default(false)
+        .prompt()?;
+
+    let mut footers = vec![];
+    let mut initial_footer = branch_name.ticket.map(|ticket| format!("Refs: {ticket}"));
+    loop {
+        let footer = Text::new("Add a footer (optional):")
+            .with_help_message("e.g. `Refs: #123`, leave empty to continue")
+            .with_initial_value(initial_footer.take().as_deref().unwrap_or_default())
+            .prompt()?;
+
+        if footer.trim().is_empty() {
+            break;
+        }
+
+        footers.push(footer.trim().to_string());
+    }
+
+    let answers = WizardAnswers {
+        commit_type: commit_type.to_string(),
+        scope,
+        summary,
+        body,
+        footer: Some(footers.join("\n")).filter(|footer| !footer.is_empty()),
+        breaking,
+    };
+
+    let message = answers.message(cocogitto.get_committer().ok())?;
+    println!("\n{message}\n");
+
+    if !Confirm::new("Commit with this message?")
+        .with_default(true)
+        .prompt()?
+    {
+        bail!("Aborted commit");
+    }
+
+    Ok(answers)
+}
+
+fn prompt_type(default: Option<&CommitType>) -> Result<CommitType> {
+    let types: Vec<TypeChoice> = COMMITS_METADATA
+        .iter()
+        .sorted_by_key(|(commit_type, config)| (config.order, commit_type.as_ref().to_string()))
+        .map(|(commit_type, config)| TypeChoice {
+            commit_type,
+            config,
+        })
+        .collect();
+
+    let starting_cursor = types
+        .iter()
+        .position(|choice| Some(choice.commit_type) == default)
+        .unwrap_or_default();
+
+    let choice = Select::new("Select the type of change:", types)
+        .with_starting_cursor(starting_cursor)
+        .prompt()?;
+
+    Ok(choice.commit_type.clone())
+}
+
+fn prompt_scope(commit_type: &CommitType, default: Option<&str>) -> Result<Option<String>> {
+    let allowed = SETTINGS.allowed_scopes(commit_type);
+    let package_names = SETTINGS
+        .monorepo
+        .as_ref()
+        .map(|monorepo| monorepo.packages.keys().cloned().collect())
+        .unwrap_or_default();
+
+    // Patterns cannot be picked from a list, allow typing a scope when some are configured
+    let accepts_custom = allowed.as_ref().map_or(true, |scopes| {
+        scopes.iter().any(|scope| is_scope_pattern(scope))
+    });
+
+    let mut choices: Vec<String> = allowed
+        .iter()
+        .flatten()
+        .filter(|scope| !is_scope_pattern(scope))
+        .cloned()
+        .chain(if allowed.is_none() {
+            package_names
+        } else {
+            vec![]
+        })
+        .unique()
+        .sorted()
+        .collect();
+
+    if choices.is_empty() {
+        if !accepts_custom {
+            return Ok(None);
+        }
+
+        let scope = Text::new("Scope of the change (optional):")
+            .with_initial_value(default.unwrap_or_default())
+            .prompt()?;
+        return Ok(Some(scope.trim().to_string()).filter(|scope| !scope.is_empty()));
+    }
+
+    choices.insert(0, NO_SCOPE.to_string());
+    if accepts_custom {
+        choices.push(CUSTOM_SCOPE.to_string());
+    }
+
+    let starting_cursor = choices
+        .iter()
+        .position(|choice| Some(choice.as_str()) == default)
+        .unwrap_or_default();
+
+    let scope = Select::new("Select the scope of the change:", choices)
+        .with_starting_cursor(starting_cursor)
+        .prompt()?;
+    match scope.as_str() {
+        NO_SCOPE => Ok(None),
+        CUSTOM_SCOPE => Ok(Some(
+            Text::new("Scope of the change:")
+                .with_validator(NotEmpty)
+                .prompt()?
+                .trim()
+                .to_string(),
+        )),
+        _ => Ok(Some(scope)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Once;
+
+    use crate::wizard::WizardAnswers;
+    use cocogitto::{set_config_path, DEFAULT_CONFIG_PATH};
+    use speculoos::prelude::*;
+
+    static CONFIG: Once = Once::new();
+
+    fn answers() -> WizardAnswers {
+        // The config path is set by `main` in the binary
+        CONFIG.call_once(|| set_config_path(DEFAULT_CONFIG_PATH.to_string()));
+
+        WizardAnswers {
+            commit_type: "feat".to_string(),
+            scope: None,
+            summary: "add login".to_string(),
+            body: None,
+            footer: None,
+            breaking: false,
+        }
+    }
+
+    #[test]
+    fn should_build_message_with_scope() {
+        let answers = WizardAnswers {
+            scope: Some("api".to_string()),
+            ..answers()
+        };
+
+        assert_that!(answers.message(None))
+            .is_ok()
+            .is_equal_to("feat(api): add login".to_string());
+    }
+
+    #[test]
+    fn should_build_breaking_change_with_body_and_footers() {
+        let answers = WizardAnswers {
+            body: Some("Sessions are now stored server side.".to_string()),
+            footer: Some("Refs: #123\nReviewed-by: Tom".to_string()),
+            breaking: true,
+            ..answers()
+        };
+
+        assert_that!(answers.message(None)).is_ok().is_equal_to(
+            "feat!: add login\n\nSessions are now stored server side.\n\nRefs: #123\nReviewed-by: Tom"
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_commit_type() {
+        let answers = WizardAnswers {
+            commit_type: "wip".to_string(),
+            ..answers()
+        };
+
+        assert_that!(answers.message(None)).is_err();
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use tera::Tera;
+
+use crate::conventional::changelog::release::Release;
+use crate::git::tag::Tag;
+use crate::settings::MonoRepoPackage;
+use crate::SETTINGS;
+
+/// The bump commit message template of standard bumps.
+pub(super) fn template() -> Option<&'static str> {
+    SETTINGS.bump_commit_message.as_deref()
+}
+
+/// The bump commit message template of a package bump.
+pub(super) fn package_template(package: &MonoRepoPackage) -> Option<&str> {
+    package.bump_commit_message.as_deref().or(template())
+}
+
+/// The bump commit message template of monorepo bumps.
+pub(super) fn monorepo_template() -> Option<&'static str> {
+    SETTINGS
+        .monorepo
+        .as_ref()
+        .and_then(|monorepo| monorepo.bump_commit_message.as_deref())
+        .or(template())
+}
+
+/// Variables available to the `bump_commit_message` template.
+#[derive(Debug, Default, Serialize)]
+pub(super) struct BumpCommitContext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_tag: Option<String>,
+    packages: Vec<PackageVersion>,
+    changelog: Vec<CommitSummary>,
+    #[serde(skip)]
+    changelog_oids: HashSet<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PackageVersion {
+    name: String,
+    version: String,
+    version_tag: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_tag: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CommitSummary {
+    #[serde(rename = "type")]
+    commit_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    summary: String,
+    breaking: bool,
+}
+
+impl BumpCommitContext {
+    /// The context of a bump from `current` to `next`, `current` is omitted on the first release.
+    pub(super) fn new(current: &Tag, next: &Tag) -> Self {
+        let current = (!current.is_zero()).then_some(current);
+
+        BumpCommitContext {
+            version: Some(next.formatted_version()),
+            version_tag: Some(next.to_string()),
+            latest: current.map(Tag::formatted_version),
+            latest_tag: current.map(Tag::to_string),
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn package(mut self, name: &str, current: Option<&Tag>, next: &Tag) -> Self {
+        let current = current.filter(|tag| !tag.is_zero());
+
+        self.packages.push(PackageVersion {
+            name: name.to_string(),
+            version: next.formatted_version(),
+            version_tag: next.to_string(),
+            latest: current.map(Tag::formatted_version),
+            latest_tag: current.map(Tag::to_string),
+        });
+        self
+    }
+
+    /// Add the commits of a release and of its previous releases to the changelog summary,
+    /// skipping commits already added.
+    pub(super) fn changelog(mut self, release: &Release) -> Self {
+        for changelog_commit in release.all_commits() {
+            let commit = &changelog_commit.commit;
+            if !self.changelog_oids.insert(commit.oid.clone()) {
+                continue;
+            }
+
+            self.changelog.push(CommitSummary {
+                commit_type: commit.conventional.commit_type.as_ref().to_string(),
+                scope: commit.conventional.scope.clone(),
+                summary: commit.conventional.summary.clone(),
+                breaking: commit.conventional.is_breaking_change,
+            });
+        }
+
+        self
+    }
+}
+
+/// Render a bump commit message template, surrounding blank lines are trimmed.
+pub(super) fn render(template: &str, context: &BumpCommitContext) -> Result<String> {
+    let context = tera::Context::from_serialize(context)?;
+    let message =
+        Tera::one_off(template, &context, false).context("failed to render bump_commit_message")?;
+    Ok(message.trim().to_string())
+}
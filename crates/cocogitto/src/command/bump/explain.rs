@@ -0,0 +1,88 @@
+use serde::Serialize;
+
+use crate::conventional::changelog::release::Release;
+use crate::conventional::commit::format_summary;
+use crate::conventional::version::Increment;
+use crate::git::tag::Tag;
+
+/// Why a version is bumped, added to the bump plan by `cog bump --explain`.
+#[derive(Debug, Default, Serialize)]
+pub struct BumpExplanation {
+    /// Every commit in range with the increment it contributes.
+    pub commits: Vec<ExplainedCommit>,
+    /// Package increments propagated to the global version of a monorepo.
+    pub packages: Vec<PackageIncrement>,
+    /// How the next version is derived from the increments.
+    pub decision: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExplainedCommit {
+    pub id: String,
+    pub summary: String,
+    pub increment: Increment,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PackageIncrement {
+    pub package: String,
+    pub increment: Increment,
+    /// Only public api packages increment the global version.
+    pub public_api: bool,
+}
+
+impl BumpExplanation {
+    /// An explanation starting with the decisions taken while computing the next version.
+    pub(super) fn new(decision: &[String]) -> Self {
+        BumpExplanation {
+            decision: decision.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn package(mut self, package: &str, increment: Increment, public_api: bool) -> Self {
+        self.packages.push(PackageIncrement {
+            package: package.to_string(),
+            increment,
+            public_api,
+        });
+        self
+    }
+
+    /// Explain the commits of the release and of its previous releases, and state the resulting version.
+    pub(super) fn finish(mut self, release: &Release, current: &Tag, next: &Tag) -> Self {
+        self.commits = release
+            .all_commits()
+            .map(|changelog_commit| &changelog_commit.commit)
+            .map(|commit| ExplainedCommit {
+                id: commit.shorthand().to_string(),
+                summary: format_summary(&commit.conventional),
+                increment: commit.increment(),
+                reason: commit.increment_reason(),
+            })
+            .collect();
+
+        let commits_increment = self
+            .commits
+            .iter()
+            .map(|commit| commit.increment)
+            .max()
+            .unwrap_or(Increment::NoBump);
+        self.decision
+            .insert(0, format!("highest commit increment: {commits_increment}"));
+
+        let increment = next
+            .get_increment_from(current)
+            .unwrap_or(Increment::NoBump);
+        let current = if current.is_zero() {
+            "none".to_string()
+        } else {
+            current.to_string()
+        };
+        self.decision
+            .push(format!("next version: {current} -> {next} ({increment})"));
+
+        self
+    }
+}
@@ -1,18 +1,23 @@
+use crate::command::bump::commit_message::BumpCommitContext;
 use crate::command::bump::prerelease::increment_prerelease;
+use crate::command::bump::transaction::BumpTransaction;
 use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::remove_releases_from_file;
+use crate::conventional::changelog::template::Template;
 use crate::conventional::commit::Commit;
 use crate::git::error::TagError;
 use crate::git::oid::OidOf;
 
 use crate::conventional::error::BumpError as ConvBumpError;
-use crate::conventional::version::IncrementCommand;
 use crate::conventional::version::PreCommand;
+use crate::conventional::version::{Increment, IncrementCommand};
 use crate::git::repository::Repository;
 use crate::git::tag::{Tag, TagLookUpOptions};
 use crate::hook::{Hook, HookVersion, Hooks};
-use crate::settings::{HookType, MonoRepoPackage, Settings};
+use crate::settings::{HookType, MonoRepoPackage, PromotedPreReleases, ReleaseBranch, Settings};
+use crate::version_files::VersionFile;
 use crate::BumpError;
-use crate::{CocoGitto, COMMITS_METADATA, SETTINGS};
+use crate::{CocoGitto, SETTINGS};
 use anyhow::Result;
 use anyhow::{bail, ensure, Context};
 use colored::Colorize;
@@ -20,16 +25,25 @@ use conventional_commit_parser::commit::CommitType;
 use globset::Glob;
 use itertools::Itertools;
 use log::{error, info, warn};
-use semver::{BuildMetadata, Prerelease};
+use semver::{BuildMetadata, Prerelease, Version};
 use std::default::Default;
 use std::fmt;
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use tera::Tera;
 
+mod commit_message;
+pub mod explain;
 mod monorepo;
 mod package;
+pub mod plan;
 mod prerelease;
 mod standard;
+mod transaction;
+
+use explain::BumpExplanation;
+pub use plan::{BumpPlan, BumpPlanFormat};
 
 #[derive(Default)]
 pub struct BumpOptions<'a> {
@@ -43,7 +57,10 @@ pub struct BumpOptions<'a> {
     pub skip_ci_override: Option<String>,
     pub skip_untracked: bool,
     pub disable_bump_commit: bool,
+    pub no_rollback: bool,
     pub include_packages: bool,
+    pub format: BumpPlanFormat,
+    pub explain: bool,
 }
 
 #[derive(Default)]
@@ -60,12 +77,17 @@ pub struct PackageBumpOptions<'a> {
     pub skip_ci_override: Option<String>,
     pub skip_untracked: bool,
     pub disable_bump_commit: bool,
+    pub no_rollback: bool,
+    pub format: BumpPlanFormat,
+    pub explain: bool,
 }
 
 struct BumpResult {
     current: Tag,
     next: Tag,
     had_commits: bool,
+    /// Decisions taken from the increment to the next version, for `--explain`.
+    decision: Vec<String>,
 }
 
 impl BumpResult {
@@ -83,10 +105,15 @@ impl<'a> BumpOptions<'a> {
         increment: Option<IncrementCommand>,
     ) -> Result<BumpResult> {
         let tag_opts = package.map(TagLookUpOptions::package).unwrap_or_default();
-        let current = match repository.get_latest_tag(tag_opts) {
-            Ok(tag) => tag,
-            Err(TagError::NoTag) => Tag::default(),
-            Err(other) => bail!(other),
+        let current = match SETTINGS.version_source(package) {
+            Some((source, path)) => repository.get_source_version(source, &path, package)?,
+            None => match repository.get_latest_tag(tag_opts) {
+                Ok(tag) => tag,
+                Err(TagError::NoTag) => {
+                    Tag::create(Version::new(0, 0, 0), package.map(str::to_string))
+                }
+                Err(other) => bail!(other),
+            },
         };
         let current_prerelease = repository
             .get_latest_tag(tag_opts.include_pre_release())
@@ -94,15 +121,35 @@ impl<'a> BumpOptions<'a> {
             .filter(|tag| *tag > current);
 
         let increment = increment.unwrap_or_else(|| self.increment.clone());
+        let mut decision = increment_decision(&increment, &current);
+
+        if increment == IncrementCommand::Major
+            && current.version.major == 0
+            && SETTINGS.initial_development(package)
+        {
+            bail!(
+                "{}:\n\t{} is in initial development, use `cog bump --graduate` to release 1.0.0\n",
+                "SemVer Error".red(),
+                current
+            );
+        }
+
         let (mut next, had_commits) = match current.bump(increment, repository) {
             Ok(tag) => (tag, true),
-            Err(ConvBumpError::NoCommitFound) if allow_empty => (current.strip_metadata(), false),
+            Err(ConvBumpError::NoCommitFound) if allow_empty => {
+                decision.push("no commit found, the version is unchanged".to_string());
+                (current.strip_metadata(), false)
+            }
             Err(other) => bail!(other),
         };
 
         // if prerelease exists, ensure the new tag is not smaller
         if let Some(pre_release) = &current_prerelease {
             if next < *pre_release {
+                decision.push(format!(
+                    "{} is lower than the {pre_release} pre-release, keeping its version core",
+                    next.version
+                ));
                 next.version.major = pre_release.version.major;
                 next.version.minor = pre_release.version.minor;
                 next.version.patch = pre_release.version.patch;
@@ -110,7 +157,7 @@ impl<'a> BumpOptions<'a> {
         }
 
         if current.version != next.version {
-            match self.pre_release {
+            match self.pre_release(repository) {
                 Some(PreCommand::Exact(pre)) => {
                     next.version.pre = Prerelease::new(pre)?;
                 }
@@ -121,8 +168,25 @@ impl<'a> BumpOptions<'a> {
                 None => {}
             }
 
+            if !next.version.pre.is_empty() {
+                decision.push(format!("pre-release `{}`", next.version.pre));
+            }
+
             if let Some(build) = self.build {
                 next.version.build = BuildMetadata::new(build)?;
+                decision.push(format!("build metadata `{build}`"));
+            }
+        }
+
+        // on a maintenance branch, ensure the version stays in the maintenance range
+        if let Some(range) = repository.maintenance_range().filter(|_| package.is_none()) {
+            if !range.contains(&next.version) {
+                bail!(
+                    "{}:\n\t{} is outside of the {} maintenance range of this branch\n",
+                    "SemVer Error".red(),
+                    next,
+                    range
+                );
             }
         }
 
@@ -140,8 +204,137 @@ impl<'a> BumpOptions<'a> {
             current,
             next,
             had_commits,
+            decision,
+        })
+    }
+
+    /// The explanation of a bump with `--explain`, starting with the given decisions.
+    fn explanation(&self, decision: &[String]) -> Option<BumpExplanation> {
+        self.explain.then(|| BumpExplanation::new(decision))
+    }
+}
+
+/// How the increment is chosen, for `--explain`.
+fn increment_decision(increment: &IncrementCommand, current: &Tag) -> Vec<String> {
+    let requested = |increment: Increment| {
+        vec![format!(
+            "{increment} increment requested, commits are ignored"
+        )]
+    };
+
+    let mut decision = match increment {
+        IncrementCommand::Major => requested(Increment::Major),
+        IncrementCommand::Minor => requested(Increment::Minor),
+        IncrementCommand::Patch => requested(Increment::Patch),
+        IncrementCommand::NoBump => requested(Increment::NoBump),
+        IncrementCommand::Manual(version) => vec![format!("version {version} requested")],
+        IncrementCommand::Graduate => vec!["graduating to 1.0.0".to_string()],
+        IncrementCommand::Promote => vec!["promoting the latest pre-release".to_string()],
+        IncrementCommand::AutoMonoRepoGlobal(Some(increment)) => vec![format!(
+            "highest public api package increment: {increment}, the global version takes the highest of both"
+        )],
+        IncrementCommand::Auto
+        | IncrementCommand::AutoPackage(_)
+        | IncrementCommand::AutoMonoRepoGlobal(None) => vec![],
+    };
+
+    let auto = matches!(
+        increment,
+        IncrementCommand::Auto
+            | IncrementCommand::AutoPackage(_)
+            | IncrementCommand::AutoMonoRepoGlobal(_)
+    );
+    if auto && current.is_initial_development() {
+        decision.push(format!(
+            "{current} is in initial development: breaking changes bump the minor version and features the patch version"
+        ));
+    }
+
+    decision
+}
+
+impl<'a> BumpOptions<'a> {
+    /// The `--pre` option, or the pre-release of the current release branch.
+    /// Promotions always release a stable version.
+    fn pre_release(&self, repository: &Repository) -> Option<PreCommand<'a>> {
+        if self.increment == IncrementCommand::Promote {
+            return None;
+        }
+
+        self.pre_release.clone().or_else(|| {
+            let pre = release_branch(repository)?.pre.as_deref()?;
+            if pre.contains('*') {
+                Some(PreCommand::Auto(pre))
+            } else {
+                Some(PreCommand::Exact(pre))
+            }
+        })
+    }
+
+    /// The bump commit message, rendered from the `bump_commit_message` template if any,
+    /// with the skip-ci pattern appended to the subject if requested.
+    fn bump_commit_message(
+        &self,
+        version: impl fmt::Display,
+        template: Option<&str>,
+        context: &BumpCommitContext,
+    ) -> Result<String> {
+        let message = match template {
+            Some(template) => commit_message::render(template, context)?,
+            None => format!("chore(version): {version}"),
+        };
+
+        let skip_ci = self
+            .skip_ci_override
+            .as_ref()
+            .or(self.skip_ci.then_some(&SETTINGS.skip_ci));
+
+        let Some(skip_ci) = skip_ci else {
+            return Ok(message);
+        };
+
+        Ok(match message.split_once('\n') {
+            Some((subject, body)) => format!("{subject} {skip_ci}\n{body}"),
+            None => format!("{message} {skip_ci}"),
         })
     }
+
+    /// The annotated tag message rendered from the `--annotated` template, if any.
+    fn tag_annotation(&self, current: &Tag, next: &Tag) -> Result<Option<String>> {
+        let Some(msg_tmpl) = &self.annotated else {
+            return Ok(None);
+        };
+
+        let mut context = tera::Context::new();
+        context.insert("latest", &current.formatted_version());
+        context.insert("version", &next.formatted_version());
+        Ok(Some(Tera::one_off(msg_tmpl, &context, false)?))
+    }
+
+    /// On promotion, the changelog covers the commits of every promoted pre-release.
+    fn changelog_release(&self, release: Release) -> Release {
+        if self.increment == IncrementCommand::Promote {
+            release.aggregate()
+        } else {
+            release
+        }
+    }
+
+    /// The bump commit message for the bump plan, `None` if the bump commit is disabled.
+    fn planned_commit_message(
+        &self,
+        version: impl fmt::Display,
+        template: Option<&str>,
+        context: &BumpCommitContext,
+    ) -> Result<Option<String>> {
+        let disable_bump_commit = self.disable_bump_commit || SETTINGS.disable_bump_commit;
+        if disable_bump_commit {
+            return Ok(None);
+        }
+
+        self.bump_commit_message(version, template, context)
+            .map(Some)
+    }
 }
 
 impl<'a> PackageBumpOptions<'a> {
@@ -157,9 +350,26 @@ impl<'a> PackageBumpOptions<'a> {
             skip_ci_override: self.skip_ci_override.clone(),
             skip_untracked: self.skip_untracked,
             disable_bump_commit: self.disable_bump_commit,
+            no_rollback: self.no_rollback,
             include_packages: false,
+            format: self.format,
+            explain: self.explain,
         }
     }
+
+    fn bump_commit_message(
+        &self,
+        version: impl fmt::Display,
+        template: Option<&str>,
+        context: &BumpCommitContext,
+    ) -> Result<String> {
+        self.common()
+            .bump_commit_message(version, template, context)
+    }
+
+    fn changelog_release(&self, release: Release) -> Release {
+        self.common().changelog_release(release)
+    }
 }
 
 struct HookRunOptions<'a> {
@@ -228,15 +438,89 @@ impl<'a> HookRunOptions<'a> {
     }
 }
 
+/// The release channel of the current branch.
+fn release_branch(repository: &Repository) -> Option<&'static ReleaseBranch> {
+    repository
+        .get_branch_shorthand()
+        .and_then(|branch| SETTINGS.release_branch(&branch))
+}
+
 impl CocoGitto {
-    fn get_bump_revspec(&mut self, current_tag: &Tag) -> String {
+    /// Whether to generate the changelog, it can be disabled globally or for the current release branch.
+    fn changelog_enabled(&self) -> bool {
+        !SETTINGS.disable_changelog
+            && release_branch(&self.repository)
+                .map_or(true, |release_branch| !release_branch.disable_changelog)
+    }
+
+    fn get_bump_revspec(&self, current_tag: &Tag) -> String {
+        // Versions read from a `version_source` have no tag to start from
+        let version_commit = current_tag.oid().filter(|_| {
+            SETTINGS
+                .version_source(current_tag.package.as_deref())
+                .is_some()
+        });
+
         if current_tag.is_zero() {
             "..".to_string()
+        } else if let Some(oid) = version_commit {
+            format!("{oid}..")
         } else {
             format!("{current_tag}..")
         }
     }
 
+    /// On promotion, remove the sections of the promoted pre-releases from the changelog
+    /// if `changelog.promoted_pre_releases` is set to "remove".
+    fn remove_promoted_pre_releases(
+        &self,
+        increment: &IncrementCommand,
+        path: &Path,
+        template: &Template,
+        current: &Tag,
+        next: &Tag,
+    ) -> Result<()> {
+        if *increment != IncrementCommand::Promote
+            || SETTINGS.changelog.promoted_pre_releases == PromotedPreReleases::Keep
+        {
+            return Ok(());
+        }
+
+        let options = current
+            .package
+            .as_deref()
+            .map(TagLookUpOptions::package)
+            .unwrap_or_default()
+            .include_pre_release();
+
+        let pre_releases: Vec<String> = self
+            .repository
+            .tag_lookup(options)?
+            .into_iter()
+            .filter(|tag| !tag.version.pre.is_empty())
+            .filter(|tag| tag > current && tag < next)
+            .map(|tag| tag.to_string())
+            .collect();
+
+        remove_releases_from_file(path, template.kind.syntax(), &pre_releases)?;
+        Ok(())
+    }
+
+    /// Run the bump steps following the pre-bump hooks: commit, tags and post-bump hooks.
+    /// On failure the bump is rolled back, unless `--no-rollback` is set.
+    fn with_rollback<F>(
+        &mut self,
+        mut transaction: BumpTransaction,
+        no_rollback: bool,
+        release: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(&mut CocoGitto, &mut BumpTransaction) -> Result<()>,
+    {
+        let result = release(self, &mut transaction);
+        transaction.finish(&self.repository, no_rollback, result)
+    }
+
     pub fn unwrap_or_stash_and_exit<T>(&mut self, tag: &Tag, result: Result<T>) -> T {
         match result {
             Ok(res) => res,
@@ -296,6 +580,28 @@ impl CocoGitto {
             }
         };
 
+        if !SETTINGS.release_branches.is_empty() {
+            if let Some(branch) = self.repository.get_branch_shorthand() {
+                let Some(release_branch) = SETTINGS.release_branch(&branch) else {
+                    let names: Vec<&str> = SETTINGS
+                        .release_branches
+                        .iter()
+                        .map(|release_branch| release_branch.name.as_str())
+                        .collect();
+                    bail!(
+                        "No release branch matched in {:?} for branch '{}', bump is not allowed",
+                        names,
+                        branch
+                    );
+                };
+
+                match &release_branch.pre {
+                    Some(pre) => info!("Release branch '{branch}': releasing '{pre}' pre-releases"),
+                    None => info!("Release branch '{branch}': releasing stable versions"),
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -351,10 +657,11 @@ impl CocoGitto {
         Ok(release)
     }
 
-    fn run_hooks(&self, options: HookRunOptions) -> Result<()> {
+    /// The hooks to run for the given options, with versions substituted.
+    fn get_hooks(&self, options: &HookRunOptions) -> Result<Vec<Hook>> {
         let settings = Settings::get(&self.repository)?;
 
-        let hooks: Vec<Hook> = match (options.package, options.hook_profile) {
+        let mut hooks: Vec<Hook> = match (options.package, options.hook_profile) {
             (None, Some(profile)) => settings
                 .get_profile_hooks(profile, options.hook_type)
                 .iter()
@@ -397,6 +704,16 @@ impl CocoGitto {
                 .try_collect()?,
         };
 
+        for hook in &mut hooks {
+            hook.insert_versions(options.current_tag, options.next_version)?;
+        }
+
+        Ok(hooks)
+    }
+
+    fn run_hooks(&self, options: HookRunOptions) -> Result<()> {
+        let hooks = self.get_hooks(&options)?;
+
         if !hooks.is_empty() {
             let hook_type = match options.hook_type {
                 HookType::PreBump => "pre-bump",
@@ -418,8 +735,7 @@ impl CocoGitto {
             }
         }
 
-        for mut hook in hooks {
-            hook.insert_versions(options.current_tag, options.next_version)?;
+        for hook in hooks {
             let command = hook.to_string();
             let command = if command.chars().count() > 78 {
                 &command[0..command.len()]
@@ -434,6 +750,67 @@ impl CocoGitto {
 
         Ok(())
     }
+
+    /// Update the version files with the new version, or print the changes as a diff on dry run.
+    /// `root` is the directory the version files are relative to, inside the repository.
+    fn update_version_files(
+        &self,
+        version_files: &[VersionFile],
+        root: &Path,
+        tag: &Tag,
+        dry_run: bool,
+    ) -> Result<()> {
+        let version = tag.formatted_version();
+
+        for version_file in version_files {
+            let update = version_file.update(self.workdir(), root, &version)?;
+            if dry_run {
+                eprint!("{}", update.diff());
+            } else {
+                update.write()?;
+                info!("\tVersion updated in {}", update.path.display());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The files written by the bump, relative to the repository root: the changelogs, written
+    /// relative to the current directory, and the version files. When the working tree was clean
+    /// before the bump, the other staged files, such as those edited by pre-bump hooks, are
+    /// included too.
+    fn bump_files(
+        &self,
+        skip_untracked: bool,
+        changelogs: Vec<PathBuf>,
+        version_files: Vec<PathBuf>,
+    ) -> Result<Vec<PathBuf>> {
+        let mut files: Vec<PathBuf> = changelogs
+            .iter()
+            .map(|path| self.repository.relative_path(path))
+            .chain(version_files)
+            .collect();
+
+        if !(skip_untracked || SETTINGS.skip_untracked) {
+            files.extend(self.repository.staged_paths()?);
+        }
+
+        Ok(files.into_iter().unique().collect())
+    }
+
+    /// The repository working directory, version files are resolved against it
+    /// rather than against the current directory.
+    pub(super) fn workdir(&self) -> &Path {
+        self.repository.get_repo_dir().expect("git repository")
+    }
+}
+
+/// The paths of the version files, relative to the repository root.
+fn version_file_paths(version_files: &[VersionFile], root: &Path) -> Vec<PathBuf> {
+    version_files
+        .iter()
+        .map(|version_file| root.join(&version_file.path))
+        .collect()
 }
 
 impl Release {
@@ -444,22 +821,11 @@ impl Release {
             .map(|ch_commit| &ch_commit.commit)
             .collect();
 
-        // Commits which type are neither feat, fix nor breaking changes
-        // won't affect the version number.
+        // Commits which don't trigger any increment won't affect the version number.
         let mut non_bump_commits: Vec<&CommitType> = conventional_commits
             .iter()
-            .filter_map(|commit: &&Commit| {
-                let commit_config = COMMITS_METADATA.get(&commit.conventional.commit_type);
-                match commit_config {
-                    Some(commit_config)
-                        if commit_config.bump_minor() || commit_config.bump_patch() =>
-                    {
-                        None
-                    }
-                    _ if commit.conventional.is_breaking_change => None,
-                    _ => Some(&commit.conventional.commit_type),
-                }
-            })
+            .filter(|commit| commit.increment() == Increment::NoBump)
+            .map(|commit| &commit.conventional.commit_type)
             .collect();
 
         non_bump_commits.sort();
@@ -478,31 +844,21 @@ impl Release {
             info!("{}", skip_message);
         }
 
-        let bump_commits =
-            conventional_commits
-                .iter()
-                .filter(|commit| match &commit.conventional.commit_type {
-                    CommitType::Feature | CommitType::BugFix => true,
-                    _commit_type if commit.conventional.is_breaking_change => true,
-                    _ => false,
-                });
+        let bump_commits = conventional_commits
+            .iter()
+            .filter(|commit| commit.increment() != Increment::NoBump);
 
         for commit in bump_commits {
-            match &commit.conventional.commit_type {
-                _commit_type if commit.conventional.is_breaking_change => {
-                    info!(
-                        "\t Found {} commit {} with type: {}",
-                        "BREAKING CHANGE".red(),
-                        commit.shorthand().blue(),
-                        commit.conventional.commit_type.as_ref().yellow()
-                    )
-                }
-                CommitType::Feature => {
-                    info!("\tFound feature commit {}", commit.shorthand().blue())
-                }
-                CommitType::BugFix => info!("\tFound bug fix commit {}", commit.shorthand().blue()),
-                _ => (),
-            }
+            let increment = match commit.increment() {
+                Increment::Major => "major".red(),
+                increment => increment.to_string().yellow(),
+            };
+
+            info!(
+                "\tFound {increment} commit {} ({})",
+                commit.shorthand().blue(),
+                commit.increment_reason()
+            );
         }
 
         Ok(())
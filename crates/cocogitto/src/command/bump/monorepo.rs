@@ -1,6 +1,12 @@
 use std::collections::HashMap;
 
-use crate::command::bump::{BumpOptions, HookRunOptions};
+use crate::command::bump::commit_message::{self, BumpCommitContext};
+use crate::command::bump::explain::BumpExplanation;
+use crate::command::bump::plan::PlannedVersion;
+use crate::command::bump::transaction::BumpTransaction;
+use crate::command::bump::{
+    version_file_paths, BumpOptions, BumpPlan, BumpPlanFormat, HookRunOptions,
+};
 use crate::conventional::changelog::context::{
     MonoRepoContext, PackageBumpContext, PackageContext,
 };
@@ -12,9 +18,10 @@ use crate::hook::HookVersion;
 use crate::settings::MonoRepoPackage;
 use crate::{settings, CocoGitto, SETTINGS};
 use anyhow::{bail, Result};
+use itertools::Itertools;
+use std::path::{Path, PathBuf};
 
 use log::{info, warn};
-use tera::Tera;
 
 use crate::git::oid::OidOf;
 
@@ -27,6 +34,7 @@ struct PackageBumpData {
     old_version: Option<HookVersion>,
     new_version: HookVersion,
     increment: Increment,
+    decision: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -64,8 +72,21 @@ impl CocoGitto {
             return Ok(());
         }
 
+        let commit_template = commit_message::monorepo_template();
+        let commit_context =
+            self.packages_commit_context(&opts, BumpCommitContext::default(), &bumps)?;
+
+        if opts.dry_run && opts.format != BumpPlanFormat::Version {
+            let versions = self.plan_packages_bumps(&opts, &bumps)?;
+            let commit_message =
+                opts.planned_commit_message("bump packages", commit_template, &commit_context)?;
+            let plan = BumpPlan::new(versions, commit_message, package_tags(&bumps));
+            return plan.print(opts.format);
+        }
+
         if opts.dry_run {
             for bump in bumps {
+                self.update_package_version_files(&bump, true)?;
                 println!("{}", bump.new_version.prefixed_tag)
             }
             return Ok(());
@@ -80,49 +101,31 @@ impl CocoGitto {
         self.unwrap_or_stash_and_exit(&Tag::default(), hook_result);
         self.bump_packages(opts.hooks_config, &bumps)?;
 
-        if !disable_bump_commit {
-            let sign = self.repository.gpg_sign();
-            if opts.skip_ci || opts.skip_ci_override.is_some() {
-                let skip_ci_pattern = opts.skip_ci_override.unwrap_or(SETTINGS.skip_ci.clone());
-                self.repository.commit(
-                    &format!("chore(version): bump packages {skip_ci_pattern}"),
-                    sign,
-                    true,
-                )?;
-            } else {
-                self.repository
-                    .commit("chore(version): bump packages", sign, true)?;
-            }
-        }
-
-        if SETTINGS.generate_mono_repository_package_tags {
-            for bump in &bumps {
-                self.repository
-                    .create_tag(&bump.new_version.prefixed_tag, disable_bump_commit)?;
+        let files = self.bump_files(
+            opts.skip_untracked,
+            self.package_changelogs(&bumps),
+            package_version_file_paths(&bumps),
+        )?;
+        let version = bumps
+            .iter()
+            .map(|bump| bump.new_version.prefixed_tag.to_string())
+            .join(", ");
+        let transaction = BumpTransaction::begin(&self.repository, version, files);
+
+        self.with_rollback(transaction, opts.no_rollback, |cog, transaction| {
+            if !disable_bump_commit {
+                let sign = cog.repository.gpg_sign();
+                let message =
+                    opts.bump_commit_message("bump packages", commit_template, &commit_context)?;
+                cog.repository.commit(&message, sign, true)?;
             }
-        }
-
-        // Run per package post hooks
-        for bump in bumps {
-            let package = SETTINGS
-                .monorepo
-                .as_ref()
-                .and_then(|m| m.packages.get(&bump.package_name))
-                .expect("package exists");
-
-            self.run_hooks(
-                HookRunOptions::post_bump()
-                    .current_tag(bump.old_version.as_ref())
-                    .next_version(&bump.new_version)
-                    .hook_profile(opts.hooks_config)
-                    .package(&bump.package_name, package),
-            )?;
-        }
 
-        // Run global post hooks
-        self.run_hooks(HookRunOptions::post_bump().hook_profile(opts.hooks_config))?;
+            cog.create_package_tags(transaction, &bumps, disable_bump_commit)?;
+            cog.run_package_post_hooks(opts.hooks_config, &bumps)?;
 
-        Ok(())
+            // Run global post hooks
+            cog.run_hooks(HookRunOptions::post_bump().hook_profile(opts.hooks_config))
+        })
     }
 
     fn create_monorepo_version_auto(&mut self, opts: BumpOptions) -> Result<()> {
@@ -154,14 +157,6 @@ impl CocoGitto {
 
         let tag = Tag::create(bump_res.next.version, None);
 
-        if opts.dry_run {
-            for bump in bumps {
-                println!("{}", bump.new_version.prefixed_tag)
-            }
-            print!("{tag}");
-            return Ok(());
-        }
-
         let mut template_context = vec![];
         for bump in &bumps {
             template_context.push(PackageBumpContext {
@@ -183,7 +178,59 @@ impl CocoGitto {
             })
         }
 
-        if !SETTINGS.disable_changelog {
+        let commit_template = commit_message::monorepo_template();
+        let mut commit_context = self.packages_commit_context(
+            &opts,
+            BumpCommitContext::new(&bump_res.current, &tag),
+            &bumps,
+        )?;
+        if commit_template.is_some() {
+            let pattern = self.get_bump_revspec(&bump_res.current);
+            let release = self.get_monorepo_global_changelog_for_version(
+                &pattern,
+                OidOf::Tag(bump_res.current.clone()),
+                tag.clone(),
+            )?;
+            commit_context = commit_context.changelog(&opts.changelog_release(release));
+        }
+
+        if opts.dry_run && opts.format != BumpPlanFormat::Version {
+            let mut versions = self.plan_packages_bumps(&opts, &bumps)?;
+            let explanation = opts.explanation(&bump_res.decision).map(|explanation| {
+                bumps.iter().fold(explanation, |explanation, bump| {
+                    explanation.package(&bump.package_name, bump.increment, bump.public_api)
+                })
+            });
+            versions.push(self.plan_monorepo_version(
+                &opts,
+                &bump_res.current,
+                &tag,
+                MonoRepoContext {
+                    package_lock: false,
+                    packages: template_context,
+                },
+                explanation,
+            )?);
+
+            let mut tags = package_tags(&bumps);
+            tags.push(tag.to_string());
+            let commit_message =
+                opts.planned_commit_message(&tag, commit_template, &commit_context)?;
+            let plan = BumpPlan::new(versions, commit_message, tags);
+            return plan.print(opts.format);
+        }
+
+        if opts.dry_run {
+            for bump in bumps {
+                self.update_package_version_files(&bump, true)?;
+                println!("{}", bump.new_version.prefixed_tag)
+            }
+            self.update_version_files(&SETTINGS.version_files, Path::new(""), &tag, true)?;
+            print!("{tag}");
+            return Ok(());
+        }
+
+        if self.changelog_enabled() {
             let pattern = self.get_bump_revspec(&bump_res.current);
             let changelog = self.get_monorepo_global_changelog_for_version(
                 &pattern,
@@ -213,83 +260,62 @@ impl CocoGitto {
             .ok();
         let next_version = HookVersion::new(tag.clone());
 
-        let hook_result = self.run_hooks(
-            HookRunOptions::pre_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config),
-        );
+        let hook_result = self
+            .update_version_files(&SETTINGS.version_files, Path::new(""), &tag, false)
+            .and_then(|_| {
+                self.run_hooks(
+                    HookRunOptions::pre_bump()
+                        .current_tag(current.as_ref())
+                        .next_version(&next_version)
+                        .hook_profile(opts.hooks_config),
+                )
+            });
 
         self.repository.add_all()?;
         self.unwrap_or_stash_and_exit(&tag, hook_result);
         self.bump_packages(opts.hooks_config, &bumps)?;
 
         let disable_bump_commit = opts.disable_bump_commit || SETTINGS.disable_bump_commit;
-
-        if !disable_bump_commit {
-            let sign = self.repository.gpg_sign();
-            if opts.skip_ci || opts.skip_ci_override.is_some() {
-                let skip_ci_pattern = opts.skip_ci_override.unwrap_or(SETTINGS.skip_ci.clone());
-                self.repository.commit(
-                    &format!(
-                        "chore(version): {} {}",
-                        next_version.prefixed_tag, skip_ci_pattern
-                    ),
-                    sign,
-                    true,
-                )?;
-            } else {
-                self.repository.commit(
-                    &format!("chore(version): {}", next_version.prefixed_tag),
-                    sign,
-                    true,
-                )?;
-            }
+        let mut changelogs = self.package_changelogs(&bumps);
+        if self.changelog_enabled() {
+            changelogs.push(settings::changelog_path().to_path_buf());
         }
-
-        if SETTINGS.generate_mono_repository_package_tags {
-            for bump in &bumps {
-                self.repository
-                    .create_tag(&bump.new_version.prefixed_tag, disable_bump_commit)?;
+        let mut version_files = package_version_file_paths(&bumps);
+        version_files.extend(version_file_paths(&SETTINGS.version_files, Path::new("")));
+        let files = self.bump_files(opts.skip_untracked, changelogs, version_files)?;
+        let transaction = BumpTransaction::begin(&self.repository, &tag, files);
+
+        self.with_rollback(transaction, opts.no_rollback, |cog, transaction| {
+            if !disable_bump_commit {
+                let sign = cog.repository.gpg_sign();
+                let message = opts.bump_commit_message(
+                    &next_version.prefixed_tag,
+                    commit_template,
+                    &commit_context,
+                )?;
+                cog.repository.commit(&message, sign, true)?;
             }
-        }
 
-        if let Some(msg_tmpl) = opts.annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &bump_res.current.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository
-                .create_annotated_tag(&tag, &msg, disable_bump_commit)?;
-        } else {
-            self.repository.create_tag(&tag, disable_bump_commit)?;
-        }
+            cog.create_package_tags(transaction, &bumps, disable_bump_commit)?;
 
-        // Run per package post hooks
-        for bump in bumps {
-            let package = SETTINGS
-                .monorepo
-                .as_ref()
-                .and_then(|m| m.packages.get(&bump.package_name))
-                .expect("package exists");
-            self.run_hooks(
-                HookRunOptions::post_bump()
-                    .current_tag(bump.old_version.as_ref())
-                    .next_version(&bump.new_version)
-                    .hook_profile(opts.hooks_config)
-                    .package(&bump.package_name, package),
+            let annotation = opts.tag_annotation(&bump_res.current, &tag)?;
+            transaction.create_tag(
+                &cog.repository,
+                &tag,
+                annotation.as_deref(),
+                disable_bump_commit,
             )?;
-        }
 
-        // Run global post hooks
-        self.run_hooks(
-            HookRunOptions::post_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config),
-        )?;
+            cog.run_package_post_hooks(opts.hooks_config, &bumps)?;
 
-        Ok(())
+            // Run global post hooks
+            cog.run_hooks(
+                HookRunOptions::post_bump()
+                    .current_tag(current.as_ref())
+                    .next_version(&next_version)
+                    .hook_profile(opts.hooks_config),
+            )
+        })
     }
 
     fn create_monorepo_version_manual(&mut self, opts: BumpOptions) -> Result<()> {
@@ -301,11 +327,6 @@ impl CocoGitto {
 
         let tag = Tag::create(bump_res.next.version, None);
 
-        if opts.dry_run {
-            print!("{tag}");
-            return Ok(());
-        }
-
         let mut template_context = vec![];
         for bump in &bumps {
             template_context.push(PackageBumpContext {
@@ -316,18 +337,69 @@ impl CocoGitto {
             })
         }
 
-        if !SETTINGS.disable_changelog {
+        let commit_template = commit_message::monorepo_template();
+        let mut commit_context = BumpCommitContext::new(&bump_res.current, &tag);
+        for package in &bumps {
+            commit_context = commit_context.package(
+                &package.package_name,
+                Some(&package.version),
+                &package.version,
+            );
+        }
+        if commit_template.is_some() {
+            let pattern = self.get_bump_revspec(&bump_res.current);
+            let release = self.get_monorepo_global_changelog_for_version(
+                &pattern,
+                OidOf::Tag(bump_res.current.clone()),
+                tag.clone(),
+            )?;
+            commit_context = commit_context.changelog(&opts.changelog_release(release));
+        }
+
+        if opts.dry_run && opts.format != BumpPlanFormat::Version {
+            let version = self.plan_monorepo_version(
+                &opts,
+                &bump_res.current,
+                &tag,
+                MonoRepoContext {
+                    package_lock: true,
+                    packages: template_context,
+                },
+                opts.explanation(&bump_res.decision),
+            )?;
+
+            let commit_message =
+                opts.planned_commit_message(&tag, commit_template, &commit_context)?;
+            let plan = BumpPlan::new(vec![version], commit_message, vec![tag.to_string()]);
+            return plan.print(opts.format);
+        }
+
+        if opts.dry_run {
+            self.update_version_files(&SETTINGS.version_files, Path::new(""), &tag, true)?;
+            print!("{tag}");
+            return Ok(());
+        }
+
+        if self.changelog_enabled() {
             let pattern = self.get_bump_revspec(&bump_res.current);
             let changelog = self.get_monorepo_global_changelog_for_version(
                 &pattern,
                 OidOf::Tag(bump_res.current.clone()),
                 tag.clone(),
             )?;
+            let changelog = opts.changelog_release(changelog);
 
             changelog.pretty_print_bump_summary()?;
 
             let path = settings::changelog_path();
             let template = SETTINGS.get_monorepo_changelog_template()?;
+            self.remove_promoted_pre_releases(
+                &opts.increment,
+                path,
+                &template,
+                &bump_res.current,
+                &tag,
+            )?;
 
             changelog.write_to_file(
                 path,
@@ -346,60 +418,59 @@ impl CocoGitto {
             .ok();
         let next_version = HookVersion::new(tag.clone());
 
-        let hook_result = self.run_hooks(
-            HookRunOptions::pre_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config),
-        );
+        let hook_result = self
+            .update_version_files(&SETTINGS.version_files, Path::new(""), &tag, false)
+            .and_then(|_| {
+                self.run_hooks(
+                    HookRunOptions::pre_bump()
+                        .current_tag(current.as_ref())
+                        .next_version(&next_version)
+                        .hook_profile(opts.hooks_config),
+                )
+            });
 
         self.repository.add_all()?;
         self.unwrap_or_stash_and_exit(&Tag::default(), hook_result);
 
         let disable_bump_commit = opts.disable_bump_commit || SETTINGS.disable_bump_commit;
+        let changelogs = self
+            .changelog_enabled()
+            .then(|| settings::changelog_path().to_path_buf());
+        let files = self.bump_files(
+            opts.skip_untracked,
+            changelogs.into_iter().collect(),
+            version_file_paths(&SETTINGS.version_files, Path::new("")),
+        )?;
+        let transaction = BumpTransaction::begin(&self.repository, &tag, files);
 
-        if !disable_bump_commit {
-            let sign = self.repository.gpg_sign();
-
-            if opts.skip_ci || opts.skip_ci_override.is_some() {
-                let skip_ci_pattern = opts.skip_ci_override.unwrap_or(SETTINGS.skip_ci.clone());
-                self.repository.commit(
-                    &format!(
-                        "chore(version): {} {}",
-                        next_version.prefixed_tag, skip_ci_pattern
-                    ),
-                    sign,
-                    true,
-                )?;
-            } else {
-                self.repository.commit(
-                    &format!("chore(version): {}", next_version.prefixed_tag),
-                    sign,
-                    true,
+        self.with_rollback(transaction, opts.no_rollback, |cog, transaction| {
+            if !disable_bump_commit {
+                let sign = cog.repository.gpg_sign();
+
+                let message = opts.bump_commit_message(
+                    &next_version.prefixed_tag,
+                    commit_template,
+                    &commit_context,
                 )?;
+                cog.repository.commit(&message, sign, true)?;
             }
-        }
-
-        if let Some(msg_tmpl) = opts.annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &bump_res.current.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository
-                .create_annotated_tag(&tag, &msg, disable_bump_commit)?;
-        } else {
-            self.repository.create_tag(&tag, disable_bump_commit)?;
-        }
 
-        // Run global post hooks
-        self.run_hooks(
-            HookRunOptions::post_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config),
-        )?;
+            let annotation = opts.tag_annotation(&bump_res.current, &tag)?;
+            transaction.create_tag(
+                &cog.repository,
+                &tag,
+                annotation.as_deref(),
+                disable_bump_commit,
+            )?;
 
-        Ok(())
+            // Run global post hooks
+            cog.run_hooks(
+                HookRunOptions::post_bump()
+                    .current_tag(current.as_ref())
+                    .next_version(&next_version)
+                    .hook_profile(opts.hooks_config),
+            )
+        })
     }
 
     pub fn get_current_packages(&self) -> Result<Vec<PackageData>> {
@@ -484,7 +555,7 @@ impl CocoGitto {
                 }
             }
         } else {
-            packages.sort_by(|a, b| a.1.bump_order.cmp(&b.1.bump_order));
+            packages.sort_by_key(|a| a.1.bump_order);
         }
 
         for (package_name, package) in packages {
@@ -518,6 +589,7 @@ impl CocoGitto {
                     old_version,
                     new_version: HookVersion::new(tag),
                     increment,
+                    decision: bump_res.decision,
                 })
             }
         }
@@ -525,6 +597,70 @@ impl CocoGitto {
         Ok(package_bumps)
     }
 
+    fn plan_packages_bumps(
+        &self,
+        opts: &BumpOptions,
+        bumps: &[PackageBumpData],
+    ) -> Result<Vec<PlannedVersion>> {
+        bumps
+            .iter()
+            .map(|bump| {
+                let package_name = bump.package_name.as_str();
+                let package = SETTINGS
+                    .monorepo
+                    .as_ref()
+                    .and_then(|m| m.packages.get(package_name))
+                    .expect("package exists");
+
+                let tag = &bump.new_version.prefixed_tag;
+                let pattern = self.get_bump_revspec(&bump.current);
+                let release = self.get_package_changelog_with_target_version(
+                    &pattern,
+                    tag.clone(),
+                    package_name,
+                )?;
+                let template = SETTINGS.get_package_changelog_template()?;
+                let release_type = ReleaseType::Package(PackageContext { package_name });
+
+                self.plan_version(
+                    Some((package_name, package)),
+                    &bump.current,
+                    tag,
+                    (release, template, release_type),
+                    opts.hooks_config,
+                    opts.explanation(&bump.decision),
+                )
+            })
+            .collect()
+    }
+
+    fn plan_monorepo_version(
+        &self,
+        opts: &BumpOptions,
+        current: &Tag,
+        tag: &Tag,
+        context: MonoRepoContext,
+        explanation: Option<BumpExplanation>,
+    ) -> Result<PlannedVersion> {
+        let pattern = self.get_bump_revspec(current);
+        let release = self.get_monorepo_global_changelog_for_version(
+            &pattern,
+            OidOf::Tag(current.clone()),
+            tag.clone(),
+        )?;
+        let release = opts.changelog_release(release);
+        let template = SETTINGS.get_monorepo_changelog_template()?;
+
+        self.plan_version(
+            None,
+            current,
+            tag,
+            (release, template, ReleaseType::MonoRepo(context)),
+            opts.hooks_config,
+            explanation,
+        )
+    }
+
     // Run pre hooks and generate changelog for each package and git add the generated content
     fn bump_packages(
         &mut self,
@@ -541,7 +677,7 @@ impl CocoGitto {
                 .and_then(|m| m.packages.get(package_name.as_str()))
                 .expect("package exists");
 
-            if !SETTINGS.disable_changelog {
+            if self.changelog_enabled() {
                 let pattern = self.get_bump_revspec(&bump.current);
                 let changelog = self.get_package_changelog_with_target_version(
                     &pattern,
@@ -570,13 +706,17 @@ impl CocoGitto {
 
             let new_version = HookVersion::new(tag.clone());
 
-            let hook_result = self.run_hooks(
-                HookRunOptions::pre_bump()
-                    .current_tag(old_version.as_ref())
-                    .next_version(&new_version)
-                    .hook_profile(hooks_config)
-                    .package(package_name, package),
-            );
+            let hook_result = self
+                .update_package_version_files(bump, false)
+                .and_then(|_| {
+                    self.run_hooks(
+                        HookRunOptions::pre_bump()
+                            .current_tag(old_version.as_ref())
+                            .next_version(&new_version)
+                            .hook_profile(hooks_config)
+                            .package(package_name, package),
+                    )
+                });
 
             self.repository.add_all()?;
             self.unwrap_or_stash_and_exit(tag, hook_result);
@@ -585,3 +725,130 @@ impl CocoGitto {
         Ok(())
     }
 }
+
+impl CocoGitto {
+    /// Add the package versions, and their commits if a commit message template is set,
+    /// to the bump commit message context.
+    fn packages_commit_context(
+        &self,
+        opts: &BumpOptions,
+        mut context: BumpCommitContext,
+        bumps: &[PackageBumpData],
+    ) -> Result<BumpCommitContext> {
+        let with_changelog = commit_message::monorepo_template().is_some();
+
+        for bump in bumps {
+            let tag = &bump.new_version.prefixed_tag;
+            let current = bump
+                .old_version
+                .as_ref()
+                .map(|version| &version.prefixed_tag);
+            context = context.package(&bump.package_name, current, tag);
+
+            if with_changelog {
+                let pattern = self.get_bump_revspec(&bump.current);
+                let release = self.get_package_changelog_with_target_version(
+                    &pattern,
+                    tag.clone(),
+                    &bump.package_name,
+                )?;
+                context = context.changelog(&opts.changelog_release(release));
+            }
+        }
+
+        Ok(context)
+    }
+
+    /// The package changelogs written by the bump.
+    fn package_changelogs(&self, bumps: &[PackageBumpData]) -> Vec<PathBuf> {
+        if !self.changelog_enabled() {
+            return vec![];
+        }
+
+        bumps
+            .iter()
+            .map(|bump| package_settings(bump).changelog_path())
+            .collect()
+    }
+
+    fn create_package_tags(
+        &self,
+        transaction: &mut BumpTransaction,
+        bumps: &[PackageBumpData],
+        disable_bump_commit: bool,
+    ) -> Result<()> {
+        if SETTINGS.generate_mono_repository_package_tags {
+            for bump in bumps {
+                transaction.create_tag(
+                    &self.repository,
+                    &bump.new_version.prefixed_tag,
+                    None,
+                    disable_bump_commit,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn update_package_version_files(&self, bump: &PackageBumpData, dry_run: bool) -> Result<()> {
+        let package = package_settings(bump);
+
+        self.update_version_files(
+            &package.version_files,
+            &package.path,
+            &bump.new_version.prefixed_tag,
+            dry_run,
+        )
+    }
+
+    // Run per package post hooks
+    fn run_package_post_hooks(
+        &self,
+        hooks_config: Option<&str>,
+        bumps: &[PackageBumpData],
+    ) -> Result<()> {
+        for bump in bumps {
+            self.run_hooks(
+                HookRunOptions::post_bump()
+                    .current_tag(bump.old_version.as_ref())
+                    .next_version(&bump.new_version)
+                    .hook_profile(hooks_config)
+                    .package(&bump.package_name, package_settings(bump)),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn package_settings(bump: &PackageBumpData) -> &'static MonoRepoPackage {
+    SETTINGS
+        .monorepo
+        .as_ref()
+        .and_then(|m| m.packages.get(&bump.package_name))
+        .expect("package exists")
+}
+
+/// Version files of the bumped packages, relative to the repository root.
+fn package_version_file_paths(bumps: &[PackageBumpData]) -> Vec<PathBuf> {
+    bumps
+        .iter()
+        .flat_map(|bump| {
+            let package = package_settings(bump);
+            version_file_paths(&package.version_files, &package.path)
+        })
+        .collect()
+}
+
+/// Package tags created by the bump, if enabled.
+fn package_tags(bumps: &[PackageBumpData]) -> Vec<String> {
+    if !SETTINGS.generate_mono_repository_package_tags {
+        return vec![];
+    }
+
+    bumps
+        .iter()
+        .map(|bump| bump.new_version.prefixed_tag.to_string())
+        .collect()
+}
@@ -1,4 +1,8 @@
-use crate::command::bump::{HookRunOptions, PackageBumpOptions};
+use crate::command::bump::commit_message::{self, BumpCommitContext};
+use crate::command::bump::transaction::BumpTransaction;
+use crate::command::bump::{
+    version_file_paths, BumpPlan, BumpPlanFormat, HookRunOptions, PackageBumpOptions,
+};
 use crate::conventional::changelog::context::PackageContext;
 use crate::conventional::changelog::ReleaseType;
 use crate::git::tag::Tag;
@@ -7,7 +11,6 @@ use crate::{CocoGitto, SETTINGS};
 use anyhow::Result;
 use colored::*;
 use log::info;
-use tera::Tera;
 
 impl CocoGitto {
     pub fn create_package_version(&mut self, opts: PackageBumpOptions) -> Result<()> {
@@ -29,23 +32,65 @@ impl CocoGitto {
             Some(opts.package_name.to_string()),
         );
 
+        if opts.dry_run && opts.format != BumpPlanFormat::Version {
+            let pattern = self.get_bump_revspec(&bump_res.current);
+            let release = self.get_package_changelog_with_target_version(
+                &pattern,
+                tag.clone(),
+                opts.package_name,
+            )?;
+            let release = opts.changelog_release(release);
+            let commit_context = BumpCommitContext::new(&bump_res.current, &tag)
+                .package(opts.package_name, Some(&bump_res.current), &tag)
+                .changelog(&release);
+            let template = SETTINGS.get_package_changelog_template()?;
+            let release_type = ReleaseType::Package(PackageContext {
+                package_name: opts.package_name,
+            });
+            let version = self.plan_version(
+                Some((opts.package_name, opts.package)),
+                &bump_res.current,
+                &tag,
+                (release, template, release_type),
+                opts.hooks_config,
+                opts.common().explanation(&bump_res.decision),
+            )?;
+
+            let commit_message = opts.common().planned_commit_message(
+                &tag,
+                commit_message::package_template(opts.package),
+                &commit_context,
+            )?;
+            let plan = BumpPlan::new(vec![version], commit_message, vec![tag.to_string()]);
+            return plan.print(opts.format);
+        }
+
         if opts.dry_run {
+            self.update_version_files(&opts.package.version_files, &opts.package.path, &tag, true)?;
             print!("{tag}");
             return Ok(());
         }
 
-        if !SETTINGS.disable_changelog {
+        if self.changelog_enabled() {
             let pattern = self.get_bump_revspec(&bump_res.current);
             let changelog = self.get_package_changelog_with_target_version(
                 &pattern,
                 tag.clone(),
                 opts.package_name,
             )?;
+            let changelog = opts.changelog_release(changelog);
 
             changelog.pretty_print_bump_summary()?;
 
             let path = opts.package.changelog_path();
             let template = SETTINGS.get_package_changelog_template()?;
+            self.remove_promoted_pre_releases(
+                &opts.increment,
+                &path,
+                &template,
+                &bump_res.current,
+                &tag,
+            )?;
             let additional_context = ReleaseType::Package(PackageContext {
                 package_name: opts.package_name,
             });
@@ -63,53 +108,71 @@ impl CocoGitto {
             Some(opts.package_name.to_string()),
         ));
 
-        let hook_result = self.run_hooks(
-            HookRunOptions::pre_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config)
-                .package(opts.package_name, opts.package),
-        );
+        let hook_result = self
+            .update_version_files(&opts.package.version_files, &opts.package.path, &tag, false)
+            .and_then(|_| {
+                self.run_hooks(
+                    HookRunOptions::pre_bump()
+                        .current_tag(current.as_ref())
+                        .next_version(&next_version)
+                        .hook_profile(opts.hooks_config)
+                        .package(opts.package_name, opts.package),
+                )
+            });
 
         self.repository.add_all()?;
         self.unwrap_or_stash_and_exit(&Tag::default(), hook_result);
 
         let disable_bump_commit = opts.disable_bump_commit || SETTINGS.disable_bump_commit;
+        let commit_template = commit_message::package_template(opts.package);
+        let mut commit_context = BumpCommitContext::new(&bump_res.current, &tag).package(
+            opts.package_name,
+            Some(&bump_res.current),
+            &tag,
+        );
+        if commit_template.is_some() {
+            let pattern = self.get_bump_revspec(&bump_res.current);
+            let release = self.get_package_changelog_with_target_version(
+                &pattern,
+                tag.clone(),
+                opts.package_name,
+            )?;
+            commit_context = commit_context.changelog(&opts.changelog_release(release));
+        }
+        let changelogs = self
+            .changelog_enabled()
+            .then(|| opts.package.changelog_path());
+        let files = self.bump_files(
+            opts.skip_untracked,
+            changelogs.into_iter().collect(),
+            version_file_paths(&opts.package.version_files, &opts.package.path),
+        )?;
+        let transaction = BumpTransaction::begin(&self.repository, &tag, files);
 
-        if !disable_bump_commit {
-            let sign = self.repository.gpg_sign();
-
-            if opts.skip_ci || opts.skip_ci_override.is_some() {
-                let skip_ci_pattern = opts.skip_ci_override.unwrap_or(SETTINGS.skip_ci.clone());
-                self.repository.commit(
-                    &format!("chore(version): {tag} {skip_ci_pattern}"),
-                    sign,
-                    true,
-                )?;
-            } else {
-                self.repository
-                    .commit(&format!("chore(version): {tag}"), sign, true)?;
+        self.with_rollback(transaction, opts.no_rollback, |cog, transaction| {
+            if !disable_bump_commit {
+                let sign = cog.repository.gpg_sign();
+
+                let message = opts.bump_commit_message(&tag, commit_template, &commit_context)?;
+                cog.repository.commit(&message, sign, true)?;
             }
-        }
 
-        if let Some(msg_tmpl) = opts.annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &bump_res.current.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository
-                .create_annotated_tag(&tag, &msg, disable_bump_commit)?;
-        } else {
-            self.repository.create_tag(&tag, disable_bump_commit)?;
-        }
+            let annotation = opts.common().tag_annotation(&bump_res.current, &tag)?;
+            transaction.create_tag(
+                &cog.repository,
+                &tag,
+                annotation.as_deref(),
+                disable_bump_commit,
+            )?;
 
-        self.run_hooks(
-            HookRunOptions::post_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config)
-                .package(opts.package_name, opts.package),
-        )?;
+            cog.run_hooks(
+                HookRunOptions::post_bump()
+                    .current_tag(current.as_ref())
+                    .next_version(&next_version)
+                    .hook_profile(opts.hooks_config)
+                    .package(opts.package_name, opts.package),
+            )
+        })?;
 
         let current = current
             .map(|current| current.prefixed_tag.to_string())
@@ -0,0 +1,304 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::command::bump::explain::BumpExplanation;
+use crate::command::bump::HookRunOptions;
+use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::template::Template;
+use crate::conventional::changelog::ReleaseType;
+use crate::conventional::commit::format_summary;
+use crate::conventional::version::Increment;
+use crate::git::tag::Tag;
+use crate::hook::HookVersion;
+use crate::settings::MonoRepoPackage;
+use crate::{CocoGitto, SETTINGS};
+
+/// Version of the `cog bump --dry-run --format json` output schema,
+/// incremented on every breaking change of the plan structure.
+pub const BUMP_PLAN_SCHEMA_VERSION: u32 = 1;
+
+/// Output format of `cog bump --dry-run`.
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BumpPlanFormat {
+    /// Print the next version only
+    #[default]
+    Version,
+    /// Print the bump plan
+    Text,
+    /// Print the bump plan as JSON
+    Json,
+}
+
+/// Everything `cog bump` would do, computed on dry run.
+#[derive(Debug, Serialize)]
+pub struct BumpPlan {
+    pub schema_version: u32,
+    /// Package versions first, then the global version if any.
+    pub versions: Vec<PlannedVersion>,
+    /// `None` if the bump commit is disabled.
+    pub commit_message: Option<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlannedVersion {
+    pub package: Option<String>,
+    pub current: Option<String>,
+    pub next: String,
+    pub increment: Increment,
+    /// Commits causing the increment.
+    pub commits: Vec<PlannedCommit>,
+    /// The changelog fragment, `None` if changelogs are disabled.
+    pub changelog: Option<String>,
+    pub version_files: Vec<PlannedVersionFile>,
+    pub pre_bump_hooks: Vec<String>,
+    pub post_bump_hooks: Vec<String>,
+    /// Why the version is bumped, with `--explain` only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<BumpExplanation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlannedCommit {
+    pub id: String,
+    pub summary: String,
+    pub breaking_change: bool,
+    pub increment: Increment,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlannedVersionFile {
+    pub path: PathBuf,
+    pub diff: String,
+}
+
+impl BumpPlan {
+    pub(super) fn new(
+        versions: Vec<PlannedVersion>,
+        commit_message: Option<String>,
+        tags: Vec<String>,
+    ) -> Self {
+        Self {
+            schema_version: BUMP_PLAN_SCHEMA_VERSION,
+            versions,
+            commit_message,
+            tags,
+        }
+    }
+
+    pub fn print(&self, format: BumpPlanFormat) -> Result<()> {
+        match format {
+            BumpPlanFormat::Json => println!("{}", serde_json::to_string_pretty(self)?),
+            BumpPlanFormat::Text | BumpPlanFormat::Version => print!("{self}"),
+        }
+
+        Ok(())
+    }
+}
+
+impl CocoGitto {
+    /// Plan a version bump without side effect. `changelog` is the release the bump would write
+    /// to the changelog, with the template and context to render it.
+    pub(super) fn plan_version(
+        &self,
+        package: Option<(&str, &MonoRepoPackage)>,
+        current: &Tag,
+        next: &Tag,
+        changelog: (Release, Template, ReleaseType),
+        hooks_config: Option<&str>,
+        explanation: Option<BumpExplanation>,
+    ) -> Result<PlannedVersion> {
+        let current_version = (!current.is_zero()).then(|| HookVersion::new(current.clone()));
+        let next_version = HookVersion::new(next.clone());
+
+        let (release, template, release_type) = changelog;
+        let commits = release
+            .all_commits()
+            .map(|changelog_commit| &changelog_commit.commit)
+            .filter(|commit| commit.increment() != Increment::NoBump)
+            .map(|commit| PlannedCommit {
+                id: commit.shorthand().to_string(),
+                summary: format_summary(&commit.conventional),
+                breaking_change: commit.conventional.is_breaking_change,
+                increment: commit.increment(),
+            })
+            .collect();
+
+        let explanation =
+            explanation.map(|explanation| explanation.finish(&release, current, next));
+
+        let changelog = if !self.changelog_enabled() {
+            None
+        } else {
+            Some(release.into_markdown(template, release_type)?)
+        };
+
+        let (version_files, root) = match package {
+            Some((_, package)) => (&package.version_files, package.path.as_path()),
+            None => (&SETTINGS.version_files, Path::new("")),
+        };
+
+        let version = next.formatted_version();
+        let version_files = version_files
+            .iter()
+            .map(|version_file| {
+                version_file
+                    .update(self.workdir(), root, &version)
+                    .map(|update| PlannedVersionFile {
+                        diff: update.diff(),
+                        path: update.path,
+                    })
+            })
+            .collect::<Result<_>>()?;
+
+        let hooks = |options: HookRunOptions| -> Result<Vec<String>> {
+            let options = options
+                .current_tag(current_version.as_ref())
+                .next_version(&next_version)
+                .hook_profile(hooks_config);
+            let options = match package {
+                Some((name, package)) => options.package(name, package),
+                None => options,
+            };
+
+            Ok(self
+                .get_hooks(&options)?
+                .iter()
+                .map(ToString::to_string)
+                .collect())
+        };
+
+        let pre_bump_hooks = hooks(HookRunOptions::pre_bump())?;
+        let post_bump_hooks = hooks(HookRunOptions::post_bump())?;
+
+        Ok(PlannedVersion {
+            package: package.map(|(name, _)| name.to_string()),
+            current: current_version.map(|version| version.prefixed_tag.to_string()),
+            next: next.to_string(),
+            increment: next
+                .get_increment_from(current)
+                .unwrap_or(Increment::NoBump),
+            commits,
+            changelog,
+            version_files,
+            pre_bump_hooks,
+            post_bump_hooks,
+            explanation,
+        })
+    }
+}
+
+impl fmt::Display for BumpPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for version in &self.versions {
+            writeln!(
+                f,
+                "{}: {} -> {} ({})",
+                version.package.as_deref().unwrap_or("version"),
+                version.current.as_deref().unwrap_or("none"),
+                version.next,
+                version.increment
+            )?;
+
+            if let Some(explanation) = &version.explanation {
+                write_explanation(f, explanation)?;
+            } else if !version.commits.is_empty() {
+                writeln!(f, "  commits:")?;
+                for commit in &version.commits {
+                    let breaking = if commit.breaking_change {
+                        " BREAKING"
+                    } else {
+                        ""
+                    };
+                    writeln!(
+                        f,
+                        "    {} {} ({}{breaking})",
+                        commit.id, commit.summary, commit.increment
+                    )?;
+                }
+            }
+
+            write_section(
+                f,
+                "version files",
+                version.version_files.iter().map(|file| file.diff.as_str()),
+            )?;
+            write_section(
+                f,
+                "pre-bump hooks",
+                version.pre_bump_hooks.iter().map(String::as_str),
+            )?;
+            write_section(
+                f,
+                "post-bump hooks",
+                version.post_bump_hooks.iter().map(String::as_str),
+            )?;
+            write_section(f, "changelog", version.changelog.as_deref())?;
+            writeln!(f)?;
+        }
+
+        match &self.commit_message {
+            Some(message) => writeln!(f, "commit: {message}")?,
+            None => writeln!(f, "commit: disabled")?,
+        }
+
+        writeln!(f, "tags: {}", self.tags.join(", "))
+    }
+}
+
+fn write_explanation(f: &mut fmt::Formatter<'_>, explanation: &BumpExplanation) -> fmt::Result {
+    if !explanation.commits.is_empty() {
+        writeln!(f, "  commits:")?;
+        for commit in &explanation.commits {
+            writeln!(
+                f,
+                "    {} {}: {} ({})",
+                commit.id, commit.summary, commit.increment, commit.reason
+            )?;
+        }
+    }
+
+    if !explanation.packages.is_empty() {
+        writeln!(f, "  packages:")?;
+        for package in &explanation.packages {
+            let propagation = if package.public_api {
+                "public api"
+            } else {
+                "not public api, ignored"
+            };
+            writeln!(
+                f,
+                "    {}: {} ({propagation})",
+                package.package, package.increment
+            )?;
+        }
+    }
+
+    write_section(
+        f,
+        "decision",
+        explanation.decision.iter().map(String::as_str),
+    )
+}
+
+fn write_section<'a>(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    entries: impl IntoIterator<Item = &'a str>,
+) -> fmt::Result {
+    let mut entries = entries.into_iter().peekable();
+    if entries.peek().is_none() {
+        return Ok(());
+    }
+
+    writeln!(f, "  {title}:")?;
+    for line in entries.flat_map(str::lines) {
+        writeln!(f, "    {line}")?;
+    }
+
+    Ok(())
+}
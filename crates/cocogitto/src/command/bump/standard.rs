@@ -1,4 +1,8 @@
-use crate::command::bump::{BumpOptions, HookRunOptions};
+use crate::command::bump::commit_message::{self, BumpCommitContext};
+use crate::command::bump::transaction::BumpTransaction;
+use crate::command::bump::{
+    version_file_paths, BumpOptions, BumpPlan, BumpPlanFormat, HookRunOptions,
+};
 
 use crate::conventional::changelog::ReleaseType;
 
@@ -8,7 +12,7 @@ use crate::{settings, CocoGitto, SETTINGS};
 use anyhow::Result;
 use colored::*;
 use log::info;
-use tera::Tera;
+use std::path::Path;
 
 impl CocoGitto {
     pub fn create_version(&mut self, opts: BumpOptions) -> Result<()> {
@@ -22,19 +26,49 @@ impl CocoGitto {
 
         let tag = Tag::create(bump_res.next.version, None);
 
+        let pattern = self.get_bump_revspec(&bump_res.current);
+
+        if opts.dry_run && opts.format != BumpPlanFormat::Version {
+            let release = self.get_changelog_with_target_version(&pattern, tag.clone())?;
+            let release = opts.changelog_release(release);
+            let commit_context =
+                BumpCommitContext::new(&bump_res.current, &tag).changelog(&release);
+            let template = SETTINGS.get_changelog_template()?;
+            let version = self.plan_version(
+                None,
+                &bump_res.current,
+                &tag,
+                (release, template, ReleaseType::Standard),
+                opts.hooks_config,
+                opts.explanation(&bump_res.decision),
+            )?;
+
+            let commit_message =
+                opts.planned_commit_message(&tag, commit_message::template(), &commit_context)?;
+            let plan = BumpPlan::new(vec![version], commit_message, vec![tag.to_string()]);
+            return plan.print(opts.format);
+        }
+
         if opts.dry_run {
+            self.update_version_files(&SETTINGS.version_files, Path::new(""), &tag, true)?;
             print!("{tag}");
             return Ok(());
         }
 
-        let pattern = self.get_bump_revspec(&bump_res.current);
-
-        if !SETTINGS.disable_changelog {
+        if self.changelog_enabled() {
             let changelog = self.get_changelog_with_target_version(&pattern, tag.clone())?;
+            let changelog = opts.changelog_release(changelog);
             changelog.pretty_print_bump_summary()?;
 
             let path = settings::changelog_path();
             let template = SETTINGS.get_changelog_template()?;
+            self.remove_promoted_pre_releases(
+                &opts.increment,
+                path,
+                &template,
+                &bump_res.current,
+                &tag,
+            )?;
 
             changelog.write_to_file(path, template, ReleaseType::Standard)?;
         }
@@ -47,57 +81,63 @@ impl CocoGitto {
 
         let next_version = HookVersion::new(tag.clone());
 
-        let hook_result = self.run_hooks(
-            HookRunOptions::pre_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config),
-        );
+        let hook_result = self
+            .update_version_files(&SETTINGS.version_files, Path::new(""), &tag, false)
+            .and_then(|_| {
+                self.run_hooks(
+                    HookRunOptions::pre_bump()
+                        .current_tag(current.as_ref())
+                        .next_version(&next_version)
+                        .hook_profile(opts.hooks_config),
+                )
+            });
 
         self.repository.add_all()?;
         self.unwrap_or_stash_and_exit(&Tag::default(), hook_result);
 
         let disable_bump_commit = opts.disable_bump_commit || SETTINGS.disable_bump_commit;
+        let mut commit_context = BumpCommitContext::new(&bump_res.current, &tag);
+        if commit_message::template().is_some() {
+            let release = self.get_changelog_with_target_version(&pattern, tag.clone())?;
+            commit_context = commit_context.changelog(&opts.changelog_release(release));
+        }
+        let changelogs = self
+            .changelog_enabled()
+            .then(|| settings::changelog_path().to_path_buf());
+        let files = self.bump_files(
+            opts.skip_untracked,
+            changelogs.into_iter().collect(),
+            version_file_paths(&SETTINGS.version_files, Path::new("")),
+        )?;
+        let transaction = BumpTransaction::begin(&self.repository, &tag, files);
 
-        if !disable_bump_commit {
-            let sign = self.repository.gpg_sign();
-
-            if opts.skip_ci || opts.skip_ci_override.is_some() {
-                let skip_ci_pattern = opts.skip_ci_override.unwrap_or(SETTINGS.skip_ci.clone());
-                self.repository.commit(
-                    &format!(
-                        "chore(version): {} {}",
-                        next_version.prefixed_tag, skip_ci_pattern
-                    ),
-                    sign,
-                    true,
-                )?;
-            } else {
-                self.repository.commit(
-                    &format!("chore(version): {}", next_version.prefixed_tag),
-                    sign,
-                    true,
+        self.with_rollback(transaction, opts.no_rollback, |cog, transaction| {
+            if !disable_bump_commit {
+                let sign = cog.repository.gpg_sign();
+
+                let message = opts.bump_commit_message(
+                    &next_version.prefixed_tag,
+                    commit_message::template(),
+                    &commit_context,
                 )?;
+                cog.repository.commit(&message, sign, true)?;
             }
-        }
 
-        if let Some(msg_tmpl) = opts.annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &bump_res.current.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository
-                .create_annotated_tag(&tag, &msg, disable_bump_commit)?;
-        } else {
-            self.repository.create_tag(&tag, disable_bump_commit)?;
-        }
-
-        self.run_hooks(
-            HookRunOptions::post_bump()
-                .current_tag(current.as_ref())
-                .next_version(&next_version)
-                .hook_profile(opts.hooks_config),
-        )?;
+            let annotation = opts.tag_annotation(&bump_res.current, &tag)?;
+            transaction.create_tag(
+                &cog.repository,
+                &tag,
+                annotation.as_deref(),
+                disable_bump_commit,
+            )?;
+
+            cog.run_hooks(
+                HookRunOptions::post_bump()
+                    .current_tag(current.as_ref())
+                    .next_version(&next_version)
+                    .hook_profile(opts.hooks_config),
+            )
+        })?;
 
         let current = current
             .map(|current| current.prefixed_tag.to_string())
@@ -0,0 +1,104 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use git2::Oid;
+use log::{info, warn};
+
+use crate::error::BumpRollbackError;
+use crate::git::repository::Repository;
+use crate::git::tag::Tag;
+
+/// The refs and files touched by the bump, used to roll the bump back if tag creation
+/// or a post-bump hook fails.
+pub(super) struct BumpTransaction {
+    version: String,
+    head: Option<Oid>,
+    files: Vec<PathBuf>,
+    tags: Vec<Tag>,
+}
+
+impl BumpTransaction {
+    /// Record the current HEAD before the bump commit is created, along with the files written
+    /// by the bump, relative to the repository root.
+    pub(super) fn begin(
+        repository: &Repository,
+        version: impl ToString,
+        files: Vec<PathBuf>,
+    ) -> Self {
+        BumpTransaction {
+            version: version.to_string(),
+            head: repository.get_head_commit_oid().ok(),
+            files,
+            tags: vec![],
+        }
+    }
+
+    pub(super) fn create_tag(
+        &mut self,
+        repository: &Repository,
+        tag: &Tag,
+        annotation: Option<&str>,
+        disable_bump_commit: bool,
+    ) -> Result<()> {
+        match annotation {
+            Some(msg) => repository.create_annotated_tag(tag, msg, disable_bump_commit)?,
+            None => repository.create_tag(tag, disable_bump_commit)?,
+        }
+
+        self.tags.push(tag.clone());
+        Ok(())
+    }
+
+    /// Roll back the bump if it failed, unless `no_rollback` is set.
+    pub(super) fn finish(
+        self,
+        repository: &Repository,
+        no_rollback: bool,
+        result: Result<()>,
+    ) -> Result<()> {
+        let Err(cause) = result else {
+            return Ok(());
+        };
+
+        if no_rollback {
+            return Err(cause);
+        }
+
+        let rollback_error = self.rollback(repository).err().map(|err| err.to_string());
+
+        Err(BumpRollbackError {
+            cause: format!("{cause:#}"),
+            version: self.version,
+            head: self.head.map(|oid| oid.to_string()),
+            tags: self.tags.iter().map(Tag::to_string).collect(),
+            files: self
+                .files
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+            rollback_error,
+        }
+        .into())
+    }
+
+    fn rollback(&self, repository: &Repository) -> Result<()> {
+        warn!("Bump to {} failed, rolling back", self.version);
+
+        for tag in &self.tags {
+            repository.delete_tag(tag)?;
+            info!("\tDeleted tag {tag}");
+        }
+
+        if let Some(head) = self.head {
+            repository.reset_mixed(head)?;
+            info!("\tHEAD reset to {head}");
+
+            for path in &self.files {
+                repository.restore_file(head, path)?;
+                info!("\tRestored {}", path.display());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -61,6 +61,14 @@ impl CocoGitto {
             packages,
         };
 
+        let changelog = self.get_monorepo_release(pattern, unified)?;
+        changelog
+            .into_markdown(template, ReleaseType::MonoRepo(context))
+            .map_err(Into::into)
+    }
+
+    /// The releases of a monorepo changelog, only the commits outside of packages unless `unified`.
+    pub fn get_monorepo_release(&self, pattern: &str, unified: bool) -> Result<Release> {
         let commit_range = if unified {
             self.repository.revwalk(pattern)?
         } else {
@@ -68,10 +76,7 @@ impl CocoGitto {
                 .get_commit_range_for_monorepo_global(pattern)?
         };
 
-        let changelog = Release::try_from(commit_range)?;
-        changelog
-            .into_markdown(template, ReleaseType::MonoRepo(context))
-            .map_err(Into::into)
+        Release::try_from(commit_range).map_err(Into::into)
     }
 
     pub fn get_changelog_at_tag(&self, tag: &str, template: Template) -> Result<String> {
@@ -16,6 +16,31 @@ impl CocoGitto {
         ignore_fixup_commits: bool,
         range: Option<String>,
     ) -> Result<()> {
+        let report = self.check_report(
+            check_from_latest_tag,
+            ignore_merge_commits,
+            ignore_fixup_commits,
+            range,
+        )?;
+
+        if report.is_empty() {
+            let msg = "No errored commits".green();
+            info!("{}", msg);
+            Ok(())
+        } else {
+            Err(anyhow!("{}", report))
+        }
+    }
+
+    /// Collect every non-compliant commit in range without failing, so the
+    /// result can be rendered in a machine-readable format.
+    pub fn check_report(
+        &self,
+        check_from_latest_tag: bool,
+        ignore_merge_commits: bool,
+        ignore_fixup_commits: bool,
+        range: Option<String>,
+    ) -> Result<CogCheckReport> {
         let commit_range = if let Some(range) = range {
             self.repository.revwalk(&range)?
         } else if check_from_latest_tag {
@@ -47,20 +72,18 @@ impl CocoGitto {
                     true
                 }
             })
-            .map(Commit::from_git_commit)
+            .map(|commit| {
+                Commit::from_git_commit(commit).and_then(|commit| {
+                    commit.lint()?;
+                    commit.check_issues()
+                })
+            })
             .filter_map(Result::err)
             .collect();
 
-        if errors.is_empty() {
-            let msg = "No errored commits".green();
-            info!("{}", msg);
-            Ok(())
-        } else {
-            let report = CogCheckReport {
-                from: commit_range.from_oid(),
-                errors: errors.into_iter().map(|err| *err).collect(),
-            };
-            Err(anyhow!("{}", report))
-        }
+        Ok(CogCheckReport {
+            from: Some(commit_range.from_oid()),
+            errors: errors.into_iter().map(|err| *err).collect(),
+        })
     }
 }
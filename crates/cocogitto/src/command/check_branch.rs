@@ -0,0 +1,65 @@
+use anyhow::{bail, Result};
+use colored::*;
+use itertools::Itertools;
+use log::info;
+
+use crate::conventional::branch::{parse_branch_name, BranchName, BranchPattern};
+use crate::{CocoGitto, COMMITS_METADATA, SETTINGS};
+
+impl CocoGitto {
+    /// Check a branch name (the current branch if `None`) against the configured `branch_patterns`.
+    pub fn check_branch(&self, branch: Option<String>) -> Result<BranchName> {
+        if SETTINGS.branch_patterns.is_empty() {
+            bail!("No `branch_patterns` configured in cog.toml");
+        }
+
+        let branch = match branch.or_else(|| self.repository.get_branch_shorthand()) {
+            Some(branch) if branch != "HEAD" => branch,
+            _ => bail!(
+                "Unable to get the current branch name (detached HEAD?), pass it as an argument"
+            ),
+        };
+
+        let Some(parsed) = parse_branch_name(&branch) else {
+            bail!(
+                "Branch '{}' does not match any of the configured patterns: {}\nKnown commit types: {}",
+                branch.red(),
+                SETTINGS
+                    .branch_patterns
+                    .iter()
+                    .map(BranchPattern::as_str)
+                    .join(", "),
+                COMMITS_METADATA
+                    .keys()
+                    .map(|commit_type| commit_type.as_ref())
+                    .sorted()
+                    .join(", ")
+            );
+        };
+
+        let parts = [
+            ("type", parsed.commit_type.as_ref().map(|t| t.to_string())),
+            ("scope", parsed.scope.clone()),
+            ("ticket", parsed.ticket.clone()),
+            ("slug", parsed.slug.clone()),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| format!("{name}: {value}")))
+        .join(", ");
+
+        if parts.is_empty() {
+            info!("{} '{}'", "Valid branch name".green(), branch);
+        } else {
+            info!("{} '{}' ({})", "Valid branch name".green(), branch, parts);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Parts of the current branch name according to the configured `branch_patterns`, if any matches.
+    pub fn current_branch_name(&self) -> Option<BranchName> {
+        self.repository
+            .get_branch_shorthand()
+            .and_then(|branch| parse_branch_name(&branch))
+    }
+}
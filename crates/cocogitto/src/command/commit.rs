@@ -2,6 +2,7 @@ use crate::conventional::commit::Commit;
 use crate::CocoGitto;
 use crate::CommitHook::CommitMessage;
 use anyhow::Result;
+use chrono::Utc;
 use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
 use conventional_commit_parser::parse_footers;
 use log::info;
@@ -42,7 +43,16 @@ impl CocoGitto {
         .to_string();
 
         // Validate the message
-        conventional_commit_parser::parse(&conventional_message)?;
+        let conventional = conventional_commit_parser::parse(&conventional_message)?;
+        let commit = Commit {
+            oid: "not committed".to_string(),
+            conventional,
+            author: self.get_committer().unwrap_or_default(),
+            date: Utc::now().naive_utc(),
+        }
+        .validate()?;
+        commit.lint()?;
+        commit.check_issues()?;
 
         if opts.add_files {
             self.repository.add_all()?;
@@ -41,7 +41,7 @@ impl CocoGitto {
                 if print_tag {
                     tag.to_string()
                 } else {
-                    tag.version.to_string()
+                    tag.formatted_version()
                 }
             }
             Err(TagError::NoTag) => match fallback {
@@ -1,9 +1,11 @@
 pub mod bump;
 pub mod changelog;
 pub mod check;
+pub mod check_branch;
 pub mod commit;
 pub mod edit;
 pub mod get_version;
 pub mod git_hooks;
 pub mod init;
 pub mod log;
+pub mod prepare_message;
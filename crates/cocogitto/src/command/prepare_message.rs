@@ -0,0 +1,186 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use conventional_commit_parser::commit::CommitType;
+use itertools::Itertools;
+use log::info;
+
+use crate::conventional::branch::BranchName;
+use crate::git::rev::filters::PackagePathFilter;
+use crate::{CocoGitto, COMMITS_METADATA, SETTINGS};
+
+impl CocoGitto {
+    /// Fill the commit message file with a commented conventional commit template,
+    /// meant to be called from the `prepare-commit-msg` git hook.
+    ///
+    /// `source` is the message source given by git as the hook's second argument,
+    /// messages coming from `-m`, `-F`, merges, squashes or amended commits are left untouched.
+    pub fn prepare_message(&self, path: &Path, source: Option<&str>) -> Result<()> {
+        if source.is_some_and(|source| !source.is_empty() && source != "template") {
+            info!(
+                "Message source is '{}', skipping",
+                source.unwrap_or_default()
+            );
+            return Ok(());
+        }
+
+        let existing = fs::read_to_string(path).unwrap_or_default();
+        let has_message = existing
+            .lines()
+            .any(|line| !line.trim().is_empty() && !line.starts_with('#'));
+
+        if has_message {
+            info!("Commit message is not empty, skipping");
+            return Ok(());
+        }
+
+        let branch = self.repository.get_branch_shorthand();
+        // The `<type>/<scope>-<description>` convention is only assumed without configured branch patterns
+        let branch_name = if SETTINGS.branch_patterns.is_empty() {
+            branch
+                .as_deref()
+                .and_then(parse_branch)
+                .map(|(commit_type, scope)| BranchName {
+                    commit_type: Some(commit_type),
+                    scope,
+                    ..Default::default()
+                })
+        } else {
+            self.current_branch_name()
+        };
+        let (branch_type, branch_scope, ticket) = match branch_name {
+            Some(name) => (name.commit_type, name.scope, name.ticket),
+            None => (None, None, None),
+        };
+
+        let packages = self.staged_packages();
+
+        // A single package touched by the staged changes is a more reliable scope than the branch name
+        let scope = match packages.as_slice() {
+            [package] => Some(package.clone()),
+            _ => branch_scope,
+        }
+        .filter(|scope| {
+            branch_type.as_ref().map_or(true, |commit_type| {
+                SETTINGS.is_scope_allowed(commit_type, scope)
+            })
+        });
+
+        let header = match (&branch_type, &scope) {
+            (Some(commit_type), Some(scope)) => format!("{commit_type}({scope}): "),
+            (Some(commit_type), None) => format!("{commit_type}: "),
+            (None, _) => String::new(),
+        };
+
+        let mut template = vec![
+            header,
+            "# Write a conventional commit message: <type>[(scope)][!]: <description>".to_string(),
+            "#".to_string(),
+            format!(
+                "# Available types: {}",
+                COMMITS_METADATA
+                    .keys()
+                    .map(|commit_type| commit_type.as_ref())
+                    .sorted()
+                    .join(", ")
+            ),
+        ];
+
+        let scopes = branch_type
+            .as_ref()
+            .and_then(|commit_type| SETTINGS.allowed_scopes(commit_type))
+            .or_else(|| SETTINGS.commit_scopes());
+
+        if let Some(scopes) = scopes {
+            template.push(format!("# Allowed scopes: {}", scopes.join(", ")));
+        }
+
+        if let Some(branch) = branch.filter(|_| branch_type.is_some()) {
+            template.push(format!("# Type inferred from branch '{branch}'"));
+        }
+
+        if let Some(ticket) = ticket {
+            template.push(format!("# Ticket: {ticket}"));
+        }
+
+        if !packages.is_empty() {
+            template.push(format!(
+                "# Staged changes touch packages: {}",
+                packages.join(", ")
+            ));
+        }
+
+        template.push("#".to_string());
+        template.push(existing);
+
+        fs::write(path, template.join("\n"))?;
+        Ok(())
+    }
+
+    /// Names of the monorepo packages matching the staged files, sorted by name.
+    fn staged_packages(&self) -> Vec<String> {
+        let Some(monorepo) = SETTINGS.monorepo.as_ref() else {
+            return vec![];
+        };
+
+        let staged = self.repository.get_staged_paths();
+
+        monorepo
+            .packages
+            .iter()
+            .filter(|(_, package)| {
+                let filter = PackagePathFilter::from_package(package);
+                staged.iter().any(|path| filter.is_match(path))
+            })
+            .map(|(name, _)| name.clone())
+            .sorted()
+            .collect()
+    }
+}
+
+/// Infer a commit type and scope from a branch name like `feat/api-login`.
+/// The type must be a known commit type, the scope is the first word of the remainder.
+fn parse_branch(branch: &str) -> Option<(CommitType, Option<String>)> {
+    let (commit_type, remainder) = branch.split_once('/')?;
+    let commit_type = CommitType::from(commit_type);
+
+    if !COMMITS_METADATA.contains_key(&commit_type) {
+        return None;
+    }
+
+    let scope = remainder
+        .split(['-', '_', '/'])
+        .next()
+        .filter(|scope| !scope.is_empty() && scope.len() < remainder.len())
+        .map(str::to_string);
+
+    Some((commit_type, scope))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_branch;
+    use conventional_commit_parser::commit::CommitType;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_parse_type_and_scope_from_branch() {
+        let parsed = parse_branch("feat/api-login");
+
+        assert_that!(parsed).is_equal_to(Some((CommitType::Feature, Some("api".to_string()))));
+    }
+
+    #[test]
+    fn should_parse_type_without_scope_from_branch() {
+        let parsed = parse_branch("fix/typo");
+
+        assert_that!(parsed).is_equal_to(Some((CommitType::BugFix, None)));
+    }
+
+    #[test]
+    fn should_ignore_unknown_branch_type() {
+        assert_that!(parse_branch("wip/api-login")).is_none();
+        assert_that!(parse_branch("main")).is_none();
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{anyhow, bail, Result};
+use conventional_commit_parser::commit::CommitType;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{COMMITS_METADATA, SETTINGS};
+
+/// Parts of a branch name extracted from a branch pattern such as `<type>/<ticket>-<slug>`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BranchName {
+    pub commit_type: Option<CommitType>,
+    pub scope: Option<String>,
+    pub ticket: Option<String>,
+    pub slug: Option<String>,
+}
+
+/// A compiled branch-name pattern.
+///
+/// Supported placeholders are `<type>`, `<scope>`, `<ticket>` and `<slug>`,
+/// `*` matches anything and every other character is matched literally.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct BranchPattern {
+    pattern: String,
+    regex: Regex,
+}
+
+impl BranchPattern {
+    pub fn new(pattern: &str) -> Result<Self> {
+        let mut regex = String::from("^");
+        let mut remainder = pattern;
+
+        while let Some(position) = remainder.find(['<', '*']) {
+            regex.push_str(&regex::escape(&remainder[..position]));
+            remainder = &remainder[position..];
+
+            if let Some(rest) = remainder.strip_prefix('*') {
+                regex.push_str(".*");
+                remainder = rest;
+                continue;
+            }
+
+            let end = remainder
+                .find('>')
+                .ok_or_else(|| anyhow!("unclosed placeholder in branch pattern '{pattern}'"))?;
+
+            let group = match &remainder[1..end] {
+                "type" => r"(?P<type>[a-zA-Z0-9_]+)",
+                "scope" => r"(?P<scope>[a-zA-Z0-9_]+)",
+                "ticket" => r"(?P<ticket>[a-zA-Z][a-zA-Z0-9]*-[0-9]+|[0-9]+)",
+                "slug" => r"(?P<slug>[a-z0-9]+(?:[._-][a-z0-9]+)*)",
+                placeholder => {
+                    bail!("unknown placeholder '<{placeholder}>' in branch pattern '{pattern}'")
+                }
+            };
+
+            regex.push_str(group);
+            remainder = &remainder[end + 1..];
+        }
+
+        regex.push_str(&regex::escape(remainder));
+        regex.push('$');
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            regex: Regex::new(&regex)?,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Match a branch name against the pattern, the `<type>` placeholder must be a known commit type.
+    pub fn parse(&self, branch: &str) -> Option<BranchName> {
+        let captures = self.regex.captures(branch)?;
+        let capture = |name| captures.name(name).map(|part| part.as_str().to_string());
+
+        let commit_type = capture("type").map(|commit_type| CommitType::from(commit_type.as_str()));
+        if commit_type
+            .as_ref()
+            .is_some_and(|commit_type| !COMMITS_METADATA.contains_key(commit_type))
+        {
+            return None;
+        }
+
+        Some(BranchName {
+            commit_type,
+            scope: capture("scope"),
+            ticket: capture("ticket"),
+            slug: capture("slug"),
+        })
+    }
+}
+
+impl PartialEq for BranchPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for BranchPattern {}
+
+impl TryFrom<String> for BranchPattern {
+    type Error = anyhow::Error;
+
+    fn try_from(pattern: String) -> Result<Self> {
+        BranchPattern::new(&pattern)
+    }
+}
+
+impl From<BranchPattern> for String {
+    fn from(pattern: BranchPattern) -> Self {
+        pattern.pattern
+    }
+}
+
+/// Parse a branch name with the configured `branch_patterns`, the first matching pattern wins.
+/// Returns `None` if no pattern is configured or none matches.
+pub fn parse_branch_name(branch: &str) -> Option<BranchName> {
+    SETTINGS
+        .branch_patterns
+        .iter()
+        .find_map(|pattern| pattern.parse(branch))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BranchName, BranchPattern};
+    use conventional_commit_parser::commit::CommitType;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_parse_type_ticket_and_slug() -> anyhow::Result<()> {
+        let pattern = BranchPattern::new("<type>/<ticket>-<slug>")?;
+
+        let parsed = pattern.parse("feat/COG-123-add-login");
+
+        assert_that!(parsed).is_equal_to(Some(BranchName {
+            commit_type: Some(CommitType::Feature),
+            scope: None,
+            ticket: Some("COG-123".to_string()),
+            slug: Some("add-login".to_string()),
+        }));
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_unknown_type() -> anyhow::Result<()> {
+        let pattern = BranchPattern::new("<type>/<slug>")?;
+
+        assert_that!(pattern.parse("wip/add-login")).is_none();
+        Ok(())
+    }
+
+    #[test]
+    fn should_match_literal_and_wildcard_patterns() -> anyhow::Result<()> {
+        assert_that!(BranchPattern::new("main")?.parse("main")).is_some();
+        assert_that!(BranchPattern::new("release/*")?.parse("release/1.x")).is_some();
+        assert_that!(BranchPattern::new("main")?.parse("main-fix")).is_none();
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_unknown_placeholder() {
+        assert_that!(BranchPattern::new("<kind>/<slug>")).is_err();
+    }
+}
@@ -1,10 +1,14 @@
-use git2::Commit as Git2Commit;
+use chrono::Local;
+use git2::{Commit as Git2Commit, Oid};
+use log::info;
 use once_cell::sync::Lazy;
 use semver::{BuildMetadata, Prerelease, Version};
 
 use crate::conventional::error::BumpError;
 use crate::conventional::version::Increment;
+use crate::git::error::TagError;
 use crate::git::tag::TagLookUpOptions;
+use crate::version_scheme::{CalVerFormat, VersionScheme};
 use crate::{Commit, IncrementCommand, Repository, Tag, SETTINGS};
 
 static FILTER_MERGE_COMMITS: Lazy<fn(&&git2::Commit) -> bool> = Lazy::new(|| {
@@ -35,7 +39,7 @@ static FILTER_FIXUP_COMMITS: Lazy<fn(&&git2::Commit) -> bool> = Lazy::new(|| {
 });
 
 pub(crate) trait Bump {
-    fn manual_bump(&self, version: &str) -> Result<Self, semver::Error>
+    fn manual_bump(&self, version: &str) -> Result<Self, BumpError>
     where
         Self: Sized;
     fn major_bump(&self) -> Self;
@@ -58,9 +62,15 @@ pub(crate) trait Bump {
 }
 
 impl Bump for Tag {
-    fn manual_bump(&self, version: &str) -> Result<Self, semver::Error> {
+    fn manual_bump(&self, version: &str) -> Result<Self, BumpError> {
         let mut next = self.clone();
-        next.version = Version::parse(version)?;
+        next.legacy_name = None;
+        next.version = match self.calver_format() {
+            Some(format) => format
+                .parse(version)
+                .ok_or_else(|| TagError::calver(version, format.as_str()))?,
+            None => Version::parse(version)?,
+        };
         Ok(next)
     }
 
@@ -103,6 +113,8 @@ impl Bump for Tag {
         Self: Sized,
     {
         let tag_from_history = self.get_monorepo_global_version_from_commit_history(repository);
+        let package_increment =
+            package_increment.map(|increment| self.initial_development_increment(increment));
         match (package_increment, tag_from_history) {
             (Some(package_increment), Ok(tag_from_history)) => {
                 let tag_from_packages = self.bump(package_increment.into(), repository)?;
@@ -132,16 +144,88 @@ impl Tag {
         repository: &Repository,
     ) -> Result<Self, BumpError> {
         match increment {
-            IncrementCommand::Major => Ok(self.major_bump()),
-            IncrementCommand::Minor => Ok(self.minor_bump()),
-            IncrementCommand::Patch => Ok(self.patch_bump()),
+            IncrementCommand::Major => Ok(self.increment(Increment::Major)),
+            IncrementCommand::Minor => Ok(self.increment(Increment::Minor)),
+            IncrementCommand::Patch => Ok(self.increment(Increment::Patch)),
             IncrementCommand::NoBump => Ok(self.no_bump()),
             IncrementCommand::Auto => self.auto_bump(repository),
             IncrementCommand::AutoPackage(package) => self.auto_package_bump(repository, &package),
             IncrementCommand::AutoMonoRepoGlobal(package_increment) => {
                 self.auto_global_bump(repository, package_increment)
             }
-            IncrementCommand::Manual(version) => self.manual_bump(&version).map_err(Into::into),
+            IncrementCommand::Manual(version) => self.manual_bump(&version),
+            IncrementCommand::Graduate => self.graduate(),
+            IncrementCommand::Promote => self.promote(repository),
+        }
+    }
+
+    /// The stable version of the latest pre-release greater than this tag.
+    fn promote(&self, repository: &Repository) -> Result<Self, BumpError> {
+        let options = self
+            .package
+            .as_deref()
+            .map(TagLookUpOptions::package)
+            .unwrap_or_default()
+            .include_pre_release();
+
+        let pre_release = repository
+            .get_latest_tag(options)
+            .ok()
+            .filter(|tag| !tag.version.pre.is_empty() && tag > self)
+            .ok_or_else(|| BumpError::NoPreRelease(Box::new(self.clone())))?;
+
+        Ok(pre_release.reset_metadata())
+    }
+
+    fn graduate(&self) -> Result<Self, BumpError> {
+        if self.version.major != 0 {
+            return Err(BumpError::AlreadyStable(self.version.clone()));
+        }
+
+        Ok(self.major_bump())
+    }
+
+    /// The calendar versioning format of the tag package, `None` for semver.
+    fn calver_format(&self) -> Option<&'static CalVerFormat> {
+        match SETTINGS.version_scheme(self.package.as_deref()) {
+            VersionScheme::SemVer => None,
+            VersionScheme::CalVer(format) => Some(format),
+        }
+    }
+
+    /// Apply an increment according to the tag package version scheme. With calendar versioning
+    /// any change releases a version for the current date, regardless of the increment kind.
+    fn increment(&self, increment: Increment) -> Self {
+        match (self.calver_format(), increment) {
+            (_, Increment::NoBump) => self.no_bump(),
+            (Some(format), _) => {
+                let mut next = self.clone();
+                next.version = format.bump(&self.version, Local::now().date_naive());
+                next.reset_metadata()
+            }
+            (None, Increment::Major) => self.major_bump(),
+            (None, Increment::Minor) => self.minor_bump(),
+            (None, Increment::Patch) => self.patch_bump(),
+        }
+    }
+
+    /// Whether the major version is 0 and `initial_development` is enabled for the tag package.
+    pub(crate) fn is_initial_development(&self) -> bool {
+        self.version.major == 0
+            && self.calver_format().is_none()
+            && SETTINGS.initial_development(self.package.as_deref())
+    }
+
+    /// During initial development, breaking changes bump the minor version and features the patch version.
+    fn initial_development_increment(&self, increment: Increment) -> Increment {
+        if !self.is_initial_development() {
+            return increment;
+        }
+
+        match increment {
+            Increment::Major => Increment::Minor,
+            Increment::Minor => Increment::Patch,
+            increment => increment,
         }
     }
 
@@ -149,13 +233,25 @@ impl Tag {
         self.version.build = BuildMetadata::EMPTY;
         self.version.pre = Prerelease::EMPTY;
         self.oid = None;
+        self.legacy_name = None;
         self
     }
 
+    /// The commit that last changed the version when it is read from a `version_source`.
+    fn version_source_oid(&self) -> Option<Oid> {
+        SETTINGS
+            .version_source(self.package.as_deref())
+            .and(self.oid)
+    }
+
     fn get_version_from_commit_history(&self, repository: &Repository) -> Result<Tag, BumpError> {
-        let changelog_start_oid = repository
-            .get_latest_tag_oid(TagLookUpOptions::default())
-            .ok()
+        let changelog_start_oid = self
+            .version_source_oid()
+            .or_else(|| {
+                repository
+                    .get_latest_tag_oid(TagLookUpOptions::default())
+                    .ok()
+            })
             .unwrap_or_else(|| repository.get_first_commit().expect("non empty repository"));
 
         let commits = repository.revwalk(&format!("{changelog_start_oid}.."))?;
@@ -174,12 +270,7 @@ impl Tag {
 
         let increment_type = self.version_increment_from_commit_history(&conventional_commits)?;
 
-        Ok(match increment_type {
-            Increment::Major => self.major_bump(),
-            Increment::Minor => self.minor_bump(),
-            Increment::Patch => self.patch_bump(),
-            Increment::NoBump => self.no_bump(),
-        })
+        Ok(self.increment(increment_type))
     }
 
     fn get_package_version_from_commit_history(
@@ -187,10 +278,9 @@ impl Tag {
         package: &str,
         repository: &Repository,
     ) -> Result<Tag, BumpError> {
-        let changelog_start_oid = repository
-            .get_latest_package_tag(package)
-            .ok()
-            .and_then(|tag| tag.oid)
+        let changelog_start_oid = self
+            .version_source_oid()
+            .or_else(|| repository.get_latest_package_tag(package).ok()?.oid)
             .unwrap_or_else(|| repository.get_first_commit().expect("non empty repository"));
 
         let commits = repository
@@ -209,21 +299,20 @@ impl Tag {
 
         let increment_type = self.version_increment_from_commit_history(&conventional_commits)?;
 
-        Ok(match increment_type {
-            Increment::Major => self.major_bump(),
-            Increment::Minor => self.minor_bump(),
-            Increment::Patch => self.patch_bump(),
-            Increment::NoBump => self.no_bump(),
-        })
+        Ok(self.increment(increment_type))
     }
 
     fn get_monorepo_global_version_from_commit_history(
         &self,
         repository: &Repository,
     ) -> Result<Tag, BumpError> {
-        let changelog_start_oid = repository
-            .get_latest_tag_oid(TagLookUpOptions::default())
-            .ok()
+        let changelog_start_oid = self
+            .version_source_oid()
+            .or_else(|| {
+                repository
+                    .get_latest_tag_oid(TagLookUpOptions::default())
+                    .ok()
+            })
             .unwrap_or_else(|| repository.get_first_commit().expect("non empty repository"));
 
         let commits =
@@ -243,23 +332,47 @@ impl Tag {
 
         let increment_type = self.version_increment_from_commit_history(&conventional_commits)?;
 
-        Ok(match increment_type {
-            Increment::Major => self.major_bump(),
-            Increment::Minor => self.minor_bump(),
-            Increment::Patch => self.patch_bump(),
-            Increment::NoBump => self.no_bump(),
-        })
+        Ok(self.increment(increment_type))
     }
 
     pub fn version_increment_from_commit_history(
         &self,
         commits: &[Commit],
     ) -> Result<Increment, BumpError> {
-        let is_major_bump = || self.version.major != 0 && commits.iter().any(Commit::is_major_bump);
+        let triggers = |increment| commits.iter().any(|commit| commit.triggers(increment));
+
+        if self.is_initial_development() {
+            let increment = [Increment::Major, Increment::Minor, Increment::Patch]
+                .into_iter()
+                .find(|increment| triggers(*increment));
+
+            return match increment {
+                Some(increment) => {
+                    let applied = self.initial_development_increment(increment);
+                    info!(
+                        "Initial development policy for {self}: {increment} changes bump the {applied} version"
+                    );
+                    Ok(applied)
+                }
+                None if !commits.is_empty() => Ok(Increment::NoBump),
+                None => Err(BumpError::NoCommitFound),
+            };
+        }
 
-        let is_minor_bump = || commits.iter().any(Commit::is_minor_bump);
+        let is_major_bump = || self.version.major != 0 && triggers(Increment::Major);
+
+        // Like breaking changes, bump rules triggering a major increment bump the minor version on 0.x
+        let is_minor_bump = || {
+            triggers(Increment::Minor)
+                || (self.version.major == 0
+                    && commits.iter().any(|commit| {
+                        commit
+                            .bump_rule()
+                            .is_some_and(|rule| rule.increment == Increment::Major)
+                    }))
+        };
 
-        let is_patch_bump = || commits.iter().any(Commit::is_patch_bump);
+        let is_patch_bump = || triggers(Increment::Patch);
 
         // At this point, it is not a major, minor or patch bump, but we might have found conventional commits
         // -> Must be only chore, docs, refactor ... which means commits that don't require bump but shouldn't throw error
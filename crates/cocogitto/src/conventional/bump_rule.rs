@@ -0,0 +1,286 @@
+use std::fmt;
+
+use conventional_commit_parser::commit::ConventionalCommit;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::conventional::version::Increment;
+
+/// # BumpRule
+/// Maps the commits matching every given condition to a version increment.
+///
+/// Rules are evaluated in order and the first matching rule takes precedence
+/// over the commit type configuration and breaking changes.
+///
+///  **Example :**
+/// ```toml
+/// [[bump_rules]]
+/// type = "fix"
+/// scope = "deps"
+/// increment = "none"
+///
+/// [[bump_rules]]
+/// footer = "Release-As: major"
+/// increment = "major"
+/// ```
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields, try_from = "BumpRuleDef")]
+pub struct BumpRule {
+    /// Commit type to match.
+    #[serde(rename = "type")]
+    pub commit_type: Option<String>,
+    /// Commit scope to match.
+    pub scope: Option<String>,
+    /// Footer to match, either a token (`Release-As`) or a token and its value (`Release-As: major`).
+    pub footer: Option<String>,
+    /// Regular expression matching the commit body.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Option<String>"))]
+    pub body: Option<BodyPattern>,
+    /// The increment for the matching commits.
+    pub increment: Increment,
+}
+
+/// The fields of a [`BumpRule`], checked for at least one condition when deserialized.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BumpRuleDef {
+    #[serde(rename = "type")]
+    commit_type: Option<String>,
+    scope: Option<String>,
+    footer: Option<String>,
+    body: Option<BodyPattern>,
+    increment: Increment,
+}
+
+impl TryFrom<BumpRuleDef> for BumpRule {
+    type Error = String;
+
+    fn try_from(rule: BumpRuleDef) -> Result<Self, Self::Error> {
+        if rule.commit_type.is_none()
+            && rule.scope.is_none()
+            && rule.footer.is_none()
+            && rule.body.is_none()
+        {
+            return Err(
+                "invalid bump rule: a bump rule needs at least one of `type`, `scope`, `footer` or `body`"
+                    .into(),
+            );
+        }
+
+        Ok(BumpRule {
+            commit_type: rule.commit_type,
+            scope: rule.scope,
+            footer: rule.footer,
+            body: rule.body,
+            increment: rule.increment,
+        })
+    }
+}
+
+/// A regular expression from `bump_rules.body`, compiled when loading the settings.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct BodyPattern(Regex);
+
+impl BodyPattern {
+    /// Compile a commit body pattern.
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        Regex::new(pattern)
+            .map(BodyPattern)
+            .map_err(|err| format!("invalid bump rule body '{pattern}': {err}"))
+    }
+
+    /// Whether the commit body matches the pattern.
+    pub fn is_match(&self, body: &str) -> bool {
+        self.0.is_match(body)
+    }
+}
+
+impl PartialEq for BodyPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for BodyPattern {}
+
+impl TryFrom<String> for BodyPattern {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        BodyPattern::parse(&pattern)
+    }
+}
+
+impl From<BodyPattern> for String {
+    fn from(pattern: BodyPattern) -> Self {
+        pattern.0.as_str().to_string()
+    }
+}
+
+impl fmt::Display for BodyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.as_str())
+    }
+}
+
+impl BumpRule {
+    /// Whether the commit matches every condition of this rule.
+    pub fn matches(&self, commit: &ConventionalCommit) -> bool {
+        let type_matches = self.commit_type.as_ref().map_or(true, |commit_type| {
+            commit_type == commit.commit_type.as_ref()
+        });
+
+        let scope_matches = self
+            .scope
+            .as_ref()
+            .map_or(true, |scope| commit.scope.as_ref() == Some(scope));
+
+        let footer_matches = self.footer.as_ref().map_or(true, |footer| {
+            let (token, value) = match footer.split_once(':') {
+                Some((token, value)) => (token.trim(), Some(value.trim())),
+                None => (footer.trim(), None),
+            };
+
+            commit.footers.iter().any(|footer| {
+                footer.token.eq_ignore_ascii_case(token)
+                    && value.map_or(true, |value| footer.content.trim() == value)
+            })
+        });
+
+        let body_matches = self.body.as_ref().map_or(true, |pattern| {
+            pattern.is_match(commit.body.as_deref().unwrap_or_default())
+        });
+
+        type_matches && scope_matches && footer_matches && body_matches
+    }
+}
+
+impl fmt::Display for BumpRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let body = self.body.as_ref().map(BodyPattern::to_string);
+        let conditions = [
+            ("type", &self.commit_type),
+            ("scope", &self.scope),
+            ("footer", &self.footer),
+            ("body", &body),
+        ];
+
+        let mut separator = "";
+        for (name, value) in conditions {
+            if let Some(value) = value {
+                write!(f, "{separator}{name} = \"{value}\"")?;
+                separator = ", ";
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use conventional_commit_parser::parse;
+    use speculoos::prelude::*;
+
+    use super::{BodyPattern, BumpRule};
+    use crate::conventional::version::Increment;
+
+    fn rule() -> BumpRule {
+        BumpRule {
+            commit_type: None,
+            scope: None,
+            footer: None,
+            body: None,
+            increment: Increment::NoBump,
+        }
+    }
+
+    #[test]
+    fn should_display_conditions() {
+        let rule = BumpRule {
+            commit_type: Some("fix".to_string()),
+            footer: Some("Release-As: major".to_string()),
+            ..rule()
+        };
+
+        assert_that!(rule.to_string())
+            .is_equal_to(r#"type = "fix", footer = "Release-As: major""#.to_string());
+    }
+
+    #[test]
+    fn should_match_type_and_scope() {
+        let rule = BumpRule {
+            commit_type: Some("fix".to_string()),
+            scope: Some("deps".to_string()),
+            ..rule()
+        };
+
+        assert_that!(rule.matches(&parse("fix(deps): bump serde").unwrap())).is_true();
+        assert_that!(rule.matches(&parse("fix(api): crash").unwrap())).is_false();
+        assert_that!(rule.matches(&parse("fix: crash").unwrap())).is_false();
+        assert_that!(rule.matches(&parse("feat(deps): add serde").unwrap())).is_false();
+    }
+
+    #[test]
+    fn should_match_footer_token_and_value() {
+        let token = BumpRule {
+            footer: Some("Release-As".to_string()),
+            ..rule()
+        };
+        let token_and_value = BumpRule {
+            footer: Some("Release-As: major".to_string()),
+            ..rule()
+        };
+
+        let major = parse("chore: release\n\nrelease-as: major").unwrap();
+        let minor = parse("chore: release\n\nRelease-As: minor").unwrap();
+
+        assert_that!(token.matches(&major)).is_true();
+        assert_that!(token.matches(&minor)).is_true();
+        assert_that!(token_and_value.matches(&major)).is_true();
+        assert_that!(token_and_value.matches(&minor)).is_false();
+    }
+
+    #[test]
+    fn should_match_body_regex() {
+        let rule = BumpRule {
+            body: BodyPattern::parse(r"^Bumps \S+ from").ok(),
+            ..rule()
+        };
+
+        let bot = parse("fix: bump serde\n\nBumps serde from 1.0.1 to 1.0.2.").unwrap();
+        let human = parse("fix: crash\n\nSome context").unwrap();
+
+        assert_that!(rule.matches(&bot)).is_true();
+        assert_that!(rule.matches(&human)).is_false();
+    }
+
+    #[test]
+    fn should_reject_rule_without_condition_or_invalid_body() {
+        let no_condition = toml::from_str::<BumpRule>(r#"increment = "major""#);
+        let invalid_body = toml::from_str::<BumpRule>(
+            r#"
+            body = "("
+            increment = "major"
+            "#,
+        );
+
+        assert_that!(no_condition).is_err();
+        assert_that!(invalid_body).is_err();
+    }
+
+    #[test]
+    fn should_deserialize_body_pattern() {
+        let rule = toml::from_str::<BumpRule>(
+            r#"
+            body = "^Bumps"
+            increment = "none"
+            "#,
+        )
+        .unwrap();
+
+        assert_that!(rule.body).is_equal_to(BodyPattern::parse("^Bumps").ok());
+    }
+}
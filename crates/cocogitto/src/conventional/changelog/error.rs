@@ -8,7 +8,12 @@ pub enum ChangelogError {
     TemplateNotFound(PathBuf),
     TeraError(tera::Error),
     WriteError(io::Error),
-    SeparatorNotFound(PathBuf),
+    InsertionPointNotFound {
+        path: PathBuf,
+        marker: String,
+        fallback: String,
+    },
+    SerializationError(String),
     EmptyRelease,
 }
 
@@ -24,11 +29,18 @@ impl Display for ChangelogError {
             ChangelogError::WriteError(err) => {
                 writeln!(f, "failed to write changelog: \n\t{err}")
             }
-            ChangelogError::SeparatorNotFound(path) => writeln!(
+            ChangelogError::InsertionPointNotFound {
+                path,
+                marker,
+                fallback,
+            } => writeln!(
                 f,
-                "cannot find default separator '- - -' in {}",
+                "cannot find where to insert the release in {}: \n\texpected the insertion marker '{marker}' on its own line{fallback}\n\tadd the marker where new releases should be inserted",
                 path.as_path().display()
             ),
+            ChangelogError::SerializationError(err) => {
+                writeln!(f, "failed to serialize changelog: \n\t{err}")
+            }
             ChangelogError::EmptyRelease => writeln!(f, "No commit found to create a changelog",),
         }
     }
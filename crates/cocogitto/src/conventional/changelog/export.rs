@@ -0,0 +1,158 @@
+use chrono::NaiveDateTime;
+use conventional_commit_parser::commit::Separator;
+use serde::Serialize;
+
+use crate::conventional::changelog::error::ChangelogError;
+use crate::conventional::changelog::release::{ChangelogCommit, Release};
+use crate::conventional::issues::IssueReference;
+use crate::conventional::version::Increment;
+use crate::git::oid::OidOf;
+use crate::{COMMITS_METADATA, SETTINGS};
+
+/// Version of the `cog changelog --format json|yaml` output schema,
+/// incremented on every breaking change of the exported structure.
+pub const RELEASE_SCHEMA_VERSION: u32 = 1;
+
+/// Output format of `cog changelog`.
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChangelogFormat {
+    /// Render the changelog template
+    #[default]
+    Markdown,
+    /// Export releases as JSON
+    Json,
+    /// Export releases as YAML
+    Yaml,
+}
+
+/// A stable, serializable view of a [`Release`] chain, newest release first.
+#[derive(Debug, Serialize)]
+pub struct ReleaseExport<'a> {
+    pub schema_version: u32,
+    pub releases: Vec<ReleaseEntry<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReleaseEntry<'a> {
+    pub version: &'a OidOf,
+    pub from: &'a OidOf,
+    pub date: NaiveDateTime,
+    pub bump: Increment,
+    pub commits: Vec<CommitEntry<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommitEntry<'a> {
+    pub id: &'a str,
+    #[serde(rename = "type")]
+    pub commit_type: &'a str,
+    pub changelog_title: Option<&'a str>,
+    pub scope: Option<&'a str>,
+    pub summary: &'a str,
+    pub body: Option<&'a str>,
+    pub breaking_change: bool,
+    pub bump: Increment,
+    pub date: NaiveDateTime,
+    pub author: AuthorEntry<'a>,
+    pub footers: Vec<FooterEntry<'a>>,
+    pub issues: Vec<IssueReference>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthorEntry<'a> {
+    pub signature: &'a str,
+    pub username: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FooterEntry<'a> {
+    pub token: &'a str,
+    pub separator: &'a str,
+    pub content: &'a str,
+}
+
+impl<'a> From<&'a Release> for ReleaseExport<'a> {
+    fn from(release: &'a Release) -> Self {
+        let mut releases = vec![];
+        let mut current = Some(release);
+
+        while let Some(release) = current {
+            releases.push(ReleaseEntry::from(release));
+            current = release.previous.as_deref();
+        }
+
+        ReleaseExport {
+            schema_version: RELEASE_SCHEMA_VERSION,
+            releases,
+        }
+    }
+}
+
+impl<'a> From<&'a Release> for ReleaseEntry<'a> {
+    fn from(release: &'a Release) -> Self {
+        let commits: Vec<CommitEntry> = release.commits.iter().map(CommitEntry::from).collect();
+
+        ReleaseEntry {
+            version: &release.version,
+            from: &release.from,
+            date: release.date,
+            bump: commits
+                .iter()
+                .map(|commit| commit.bump)
+                .max()
+                .unwrap_or(Increment::NoBump),
+            commits,
+        }
+    }
+}
+
+impl<'a> From<&'a ChangelogCommit> for CommitEntry<'a> {
+    fn from(changelog_commit: &'a ChangelogCommit) -> Self {
+        let commit = &changelog_commit.commit;
+        let conventional = &commit.conventional;
+
+        CommitEntry {
+            id: &commit.oid,
+            commit_type: conventional.commit_type.as_ref(),
+            changelog_title: COMMITS_METADATA
+                .get(&conventional.commit_type)
+                .and_then(|config| config.changelog_title.as_deref()),
+            scope: conventional.scope.as_deref(),
+            summary: &conventional.summary,
+            body: conventional.body.as_deref(),
+            breaking_change: conventional.is_breaking_change,
+            bump: commit.increment(),
+            date: commit.date,
+            author: AuthorEntry {
+                signature: &commit.author,
+                username: changelog_commit.author_username.as_deref(),
+            },
+            footers: conventional
+                .footers
+                .iter()
+                .map(|footer| FooterEntry {
+                    token: &footer.token,
+                    separator: match footer.token_separator {
+                        Separator::Colon | Separator::ColonWithNewLine => ":",
+                        Separator::Hash => "#",
+                    },
+                    content: &footer.content,
+                })
+                .collect(),
+            issues: SETTINGS.issues.references(conventional),
+        }
+    }
+}
+
+impl Release {
+    pub fn to_json(&self) -> Result<String, ChangelogError> {
+        serde_json::to_string_pretty(&ReleaseExport::from(self))
+            .map_err(|err| ChangelogError::SerializationError(err.to_string()))
+    }
+
+    pub fn to_yaml(&self) -> Result<String, ChangelogError> {
+        serde_yaml::to_string(&ReleaseExport::from(self))
+            .map_err(|err| ChangelogError::SerializationError(err.to_string()))
+    }
+}
@@ -70,3 +70,69 @@ pub fn group_by_type(value: &Value, _: &HashMap<String, Value>) -> Result<Value,
 
     Ok(to_value(out_vec).unwrap())
 }
+
+const KEEP_A_CHANGELOG_SECTIONS: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
+fn keepachangelog_section(commit_type: &str) -> &'static str {
+    match commit_type {
+        "feat" => "Added",
+        "fix" => "Fixed",
+        "remove" | "removed" => "Removed",
+        "deprecate" | "deprecated" => "Deprecated",
+        "security" | "sec" => "Security",
+        _ => "Changed",
+    }
+}
+
+// group commits by Keep a Changelog section (Added, Changed, Deprecated, Removed, Fixed, Security)
+pub fn group_by_keepachangelog_section(
+    value: &Value,
+    _: &HashMap<String, Value>,
+) -> Result<Value, tera::Error> {
+    let arr = try_get_value!(
+        "group_by_keepachangelog_section",
+        "commit_type",
+        Vec<Value>,
+        value
+    );
+    let mut map: HashMap<&str, Vec<Value>> = HashMap::new();
+
+    for v in arr {
+        let commit_type = dotted_pointer(&v, "commit_type")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        map.entry(keepachangelog_section(commit_type))
+            .or_default()
+            .push(v);
+    }
+
+    let out_vec: Vec<_> = KEEP_A_CHANGELOG_SECTIONS
+        .iter()
+        .filter_map(|section| map.remove(section).map(|commits| (section, commits)))
+        .collect();
+
+    Ok(to_value(out_vec).unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use speculoos::prelude::*;
+
+    use super::keepachangelog_section;
+
+    #[test]
+    fn should_map_commit_types_to_keepachangelog_sections() {
+        assert_that!(keepachangelog_section("feat")).is_equal_to("Added");
+        assert_that!(keepachangelog_section("fix")).is_equal_to("Fixed");
+        assert_that!(keepachangelog_section("remove")).is_equal_to("Removed");
+        assert_that!(keepachangelog_section("revert")).is_equal_to("Changed");
+        assert_that!(keepachangelog_section("chore")).is_equal_to("Changed");
+    }
+}
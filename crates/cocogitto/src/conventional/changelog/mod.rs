@@ -2,13 +2,20 @@ use crate::conventional::changelog::context::{MonoRepoContext, PackageContext};
 use crate::conventional::changelog::release::Release;
 
 use crate::conventional::changelog::error::ChangelogError;
-use crate::conventional::changelog::template::Template;
+use crate::conventional::changelog::template::{
+    join_link_definitions, split_link_definitions, ChangelogSyntax, Template,
+};
 
+use crate::settings::InsertionFallback;
+use crate::SETTINGS;
+
+use log::info;
 use std::fs;
 use std::path::Path;
 
 pub mod context;
 pub mod error;
+pub mod export;
 pub mod filters;
 pub(crate) mod release;
 pub(crate) mod serde;
@@ -26,6 +33,14 @@ See [conventional commits](https://www.conventionalcommits.org/) for commit guid
 const DEFAULT_FOOTER: &str =
     "Changelog generated by [cocogitto](https://github.com/cocogitto/cocogitto).";
 
+const KEEP_A_CHANGELOG_HEADER: &str =
+    "# Changelog\n\nAll notable changes to this project will be documented in this file.\n\n\
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\n\
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).\n";
+
+const ASCIIDOC_HEADER: &str =
+    "= Changelog\n\nAll notable changes to this project will be documented in this file.\n";
+
 pub enum ReleaseType<'a> {
     Standard,
     MonoRepo(MonoRepoContext<'a>),
@@ -53,26 +68,299 @@ impl Release {
         template: Template,
         kind: ReleaseType,
     ) -> Result<(), ChangelogError> {
-        let mut changelog_content = fs::read_to_string(path.as_ref())
-            .unwrap_or_else(|_| [DEFAULT_HEADER, DEFAULT_FOOTER].join(""));
-
+        let syntax = template.kind.syntax();
+        let existing = fs::read_to_string(path.as_ref()).ok();
         let changelog = self.into_markdown(template, kind)?;
-        let separator_idx = changelog_content.find(CHANGELOG_SEPARATOR);
-
-        if let Some(idx) = separator_idx {
-            changelog_content.insert(idx + CHANGELOG_SEPARATOR.len(), '\n');
-            changelog_content.insert_str(idx + CHANGELOG_SEPARATOR.len() + 1, &changelog);
-            changelog_content.insert_str(
-                idx + CHANGELOG_SEPARATOR.len() + 1 + changelog.len(),
-                "\n- - -\n",
+        let insertion = Insertion {
+            syntax,
+            marker: &SETTINGS.changelog.insertion_marker,
+            fallback: SETTINGS.changelog.insertion_fallback,
+        };
+
+        let Some((changelog_content, migrated)) = insertion.apply(existing, &changelog) else {
+            return Err(ChangelogError::InsertionPointNotFound {
+                path: path.as_ref().to_path_buf(),
+                marker: insertion.marker.to_string(),
+                fallback: insertion.describe_fallback(),
+            });
+        };
+
+        if migrated {
+            info!(
+                "Added insertion marker '{}' to {}",
+                insertion.marker,
+                path.as_ref().display()
             );
-            fs::write(path.as_ref(), changelog_content)?;
+        }
+
+        fs::write(path.as_ref(), changelog_content)?;
+        Ok(())
+    }
+}
+
+/// Remove the sections of the given release tags from an existing changelog file,
+/// does nothing if the file does not exist.
+pub fn remove_releases_from_file<S: AsRef<Path>>(
+    path: S,
+    syntax: ChangelogSyntax,
+    tags: &[String],
+) -> Result<(), ChangelogError> {
+    let Ok(content) = fs::read_to_string(path.as_ref()) else {
+        return Ok(());
+    };
+
+    let changelog = remove_releases(&content, syntax, tags);
+    if changelog != content {
+        fs::write(path.as_ref(), changelog)?;
+    }
+
+    Ok(())
+}
+
+/// Remove the sections of the given release tags, a section starts with a release heading
+/// and ends with the `- - -` separator for the cocogitto syntax, or with the next heading.
+fn remove_releases(content: &str, syntax: ChangelogSyntax, tags: &[String]) -> String {
+    let (content, mut links) = match syntax {
+        ChangelogSyntax::KeepAChangelog => split_link_definitions(content),
+        _ => (content.to_string(), vec![]),
+    };
+
+    let heading = match syntax {
+        ChangelogSyntax::Cocogitto | ChangelogSyntax::KeepAChangelog => "## ",
+        ChangelogSyntax::Asciidoc => "== ",
+    };
+
+    let mut result = String::with_capacity(content.len());
+    let mut in_section = false;
+
+    for line in content.split_inclusive('\n') {
+        if line.starts_with(heading) {
+            in_section = tags.iter().any(|tag| heading_mentions(line, tag));
+        }
+
+        if !in_section {
+            result.push_str(line);
+        } else if syntax == ChangelogSyntax::Cocogitto && line.trim_end() == CHANGELOG_SEPARATOR {
+            in_section = false;
+        }
+    }
+
+    if syntax == ChangelogSyntax::KeepAChangelog {
+        links.retain(|(label, _)| !tags.contains(label));
+        result = join_link_definitions(&result, &links);
+    }
+
+    result
+}
+
+/// Whether the heading mentions the tag, not as part of a longer version (`1.0.0-rc.1` in `1.0.0-rc.10`)
+/// nor as the start of a compare range (`1.0.0-rc.1..1.0.0-rc.2`).
+fn heading_mentions(heading: &str, tag: &str) -> bool {
+    let is_version_char = |char: char| char.is_ascii_alphanumeric() || "-.+".contains(char);
+
+    heading.match_indices(tag).any(|(start, _)| {
+        let before = heading[..start].chars().next_back();
+        let after = heading[start + tag.len()..].chars().next();
+        !before.is_some_and(is_version_char) && !after.is_some_and(is_version_char)
+    })
+}
+
+/// Where and how a rendered release is inserted in a changelog file.
+struct Insertion<'a> {
+    syntax: ChangelogSyntax,
+    marker: &'a str,
+    fallback: InsertionFallback,
+}
+
+impl Insertion<'_> {
+    /// Insert the release in the changelog content, or in a new changelog if `None`.
+    /// Returns the new content and whether the insertion marker was added,
+    /// or `None` if neither the marker nor the fallback insertion point were found.
+    fn apply(&self, content: Option<String>, release: &str) -> Option<(String, bool)> {
+        let content = content.unwrap_or_else(|| self.default_changelog());
+
+        let (content, links) = match self.syntax {
+            ChangelogSyntax::KeepAChangelog => split_link_definitions(&content),
+            _ => (content, vec![]),
+        };
+
+        let (release, mut release_links) = match self.syntax {
+            ChangelogSyntax::KeepAChangelog => split_link_definitions(release),
+            _ => (release.to_string(), vec![]),
+        };
+
+        let content = match self.syntax {
+            ChangelogSyntax::Cocogitto => content,
+            ChangelogSyntax::KeepAChangelog => remove_section(&content, "## ", "## [Unreleased]"),
+            ChangelogSyntax::Asciidoc => remove_section(&content, "== ", "== Unreleased"),
+        };
+
+        let (index, migrate) = match find_line(&content, |line| line == self.marker) {
+            Some((_, end)) => (end, false),
+            None => (self.fallback_index(&content)?, true),
+        };
+
+        let marker = self.marker().filter(|_| migrate);
+        let (before, after) = content.split_at(index);
+        let mut changelog = before.to_string();
+
+        match self.syntax {
+            ChangelogSyntax::Cocogitto => {
+                if !changelog.is_empty() && !changelog.ends_with('\n') {
+                    changelog.push('\n');
+                }
 
-            Ok(())
-        } else {
-            Err(ChangelogError::SeparatorNotFound(
-                path.as_ref().to_path_buf(),
-            ))
+                if let Some(marker) = marker {
+                    changelog.push_str(marker);
+                    changelog.push('\n');
+                }
+
+                changelog.push_str(&release);
+                changelog.push_str("\n- - -\n");
+                changelog.push_str(after);
+            }
+            ChangelogSyntax::KeepAChangelog | ChangelogSyntax::Asciidoc => {
+                changelog.truncate(changelog.trim_end().len());
+                if !changelog.is_empty() {
+                    changelog.push_str("\n\n");
+                }
+
+                if let Some(marker) = marker {
+                    changelog.push_str(marker);
+                    changelog.push_str("\n\n");
+                }
+
+                changelog.push_str(release.trim());
+                changelog.push('\n');
+
+                let after = after.trim_start();
+                if !after.is_empty() {
+                    changelog.push('\n');
+                    changelog.push_str(after);
+                }
+            }
+        }
+
+        if self.syntax == ChangelogSyntax::KeepAChangelog {
+            for link in links {
+                if !release_links.iter().any(|(label, _)| *label == link.0) {
+                    release_links.push(link);
+                }
+            }
+
+            changelog = join_link_definitions(&changelog, &release_links);
+        }
+
+        Some((changelog, marker.is_some()))
+    }
+
+    /// The insertion marker as written to changelogs, html comments are not valid AsciiDoc
+    /// so they are never added to AsciiDoc changelogs.
+    fn marker(&self) -> Option<&str> {
+        match self.syntax {
+            ChangelogSyntax::Asciidoc if self.marker.starts_with("<!--") => None,
+            _ => Some(self.marker),
+        }
+    }
+
+    fn fallback(&self) -> InsertionFallback {
+        match (self.fallback, self.syntax) {
+            (InsertionFallback::Auto, ChangelogSyntax::Cocogitto) => InsertionFallback::Separator,
+            (InsertionFallback::Auto, _) => InsertionFallback::Heading,
+            (fallback, _) => fallback,
+        }
+    }
+
+    fn heading(&self) -> &'static str {
+        match self.syntax {
+            ChangelogSyntax::Cocogitto | ChangelogSyntax::KeepAChangelog => "## ",
+            ChangelogSyntax::Asciidoc => "== ",
+        }
+    }
+
+    fn fallback_index(&self, content: &str) -> Option<usize> {
+        match self.fallback() {
+            InsertionFallback::Separator => {
+                find_line(content, |line| line == CHANGELOG_SEPARATOR).map(|(_, end)| end)
+            }
+            InsertionFallback::Heading => Some(
+                find_line(content, |line| line.starts_with(self.heading()))
+                    .map_or(content.len(), |(start, _)| start),
+            ),
+            InsertionFallback::Auto | InsertionFallback::None => None,
+        }
+    }
+
+    fn describe_fallback(&self) -> String {
+        match self.fallback() {
+            InsertionFallback::Separator => {
+                format!(" or a '{CHANGELOG_SEPARATOR}' line (insertion_fallback = \"separator\")")
+            }
+            InsertionFallback::Heading => format!(
+                " or a '{}' release heading (insertion_fallback = \"heading\")",
+                self.heading().trim_end()
+            ),
+            InsertionFallback::Auto | InsertionFallback::None => {
+                " (insertion_fallback = \"none\")".to_string()
+            }
         }
     }
+
+    fn default_changelog(&self) -> String {
+        let header = match self.syntax {
+            ChangelogSyntax::Cocogitto => DEFAULT_HEADER,
+            ChangelogSyntax::KeepAChangelog => KEEP_A_CHANGELOG_HEADER,
+            ChangelogSyntax::Asciidoc => ASCIIDOC_HEADER,
+        };
+
+        let mut changelog = header.to_string();
+        if let Some(marker) = self.marker() {
+            if self.syntax != ChangelogSyntax::Cocogitto {
+                changelog.push('\n');
+            }
+            changelog.push_str(marker);
+            changelog.push('\n');
+        }
+
+        if self.syntax == ChangelogSyntax::Cocogitto {
+            changelog.push('\n');
+            changelog.push_str(DEFAULT_FOOTER);
+        }
+
+        changelog
+    }
+}
+
+/// Byte range of the first line matching the predicate, the end includes the line feed.
+fn find_line(content: &str, predicate: impl Fn(&str) -> bool) -> Option<(usize, usize)> {
+    let mut start = 0;
+
+    for line in content.split_inclusive('\n') {
+        let end = start + line.len();
+        if predicate(line.trim_end()) {
+            return Some((start, end));
+        }
+        start = end;
+    }
+
+    None
+}
+
+/// Remove the section starting with the `section` heading up to the next `heading`,
+/// used to replace a previously generated unreleased section.
+fn remove_section(content: &str, heading: &str, section: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut in_section = false;
+
+    for line in content.split_inclusive('\n') {
+        if line.starts_with(heading) {
+            in_section = line.starts_with(section);
+        }
+
+        if !in_section {
+            result.push_str(line);
+        }
+    }
+
+    result
 }
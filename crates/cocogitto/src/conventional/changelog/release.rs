@@ -101,6 +101,27 @@ impl TryFrom<CommitIter<'_>> for Release {
     }
 }
 
+impl Release {
+    /// Merge the previous releases of the range into this one, used to render a single
+    /// section covering the promoted pre-releases.
+    pub fn aggregate(mut self) -> Self {
+        while let Some(previous) = self.previous.take() {
+            let previous = *previous;
+            self.commits.extend(previous.commits);
+            self.from = previous.from;
+            self.previous = previous.previous;
+        }
+
+        self
+    }
+
+    /// The commits of this release followed by the commits of its previous releases.
+    pub fn all_commits(&self) -> impl Iterator<Item = &ChangelogCommit> {
+        std::iter::successors(Some(self), |release| release.previous.as_deref())
+            .flat_map(|release| &release.commits)
+    }
+}
+
 #[derive(Debug)]
 pub struct ChangelogCommit {
     pub author_username: Option<String>,
@@ -4,7 +4,7 @@ use serde::{Serialize, Serializer};
 use crate::conventional::changelog::release::{ChangelogCommit, ChangelogFooter};
 use crate::git::oid::OidOf;
 use crate::git::tag::Tag;
-use crate::COMMITS_METADATA;
+use crate::{COMMITS_METADATA, SETTINGS};
 
 impl Serialize for Tag {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -20,7 +20,7 @@ impl Serialize for ChangelogCommit {
     where
         S: Serializer,
     {
-        let mut commit = serializer.serialize_struct("Commit", 10)?;
+        let mut commit = serializer.serialize_struct("Commit", 12)?;
 
         let footers = &self
             .commit
@@ -46,6 +46,7 @@ impl Serialize for ChangelogCommit {
         commit.serialize_field("author", &self.author_username)?;
         commit.serialize_field("signature", &self.commit.author)?;
         commit.serialize_field("type", commit_type)?;
+        commit.serialize_field("commit_type", self.commit.conventional.commit_type.as_ref())?;
         commit.serialize_field("date", &self.commit.date)?;
         commit.serialize_field("scope", &self.commit.conventional.scope)?;
         commit.serialize_field("summary", &self.commit.conventional.summary)?;
@@ -56,6 +57,10 @@ impl Serialize for ChangelogCommit {
             &self.commit.conventional.is_breaking_change,
         )?;
         commit.serialize_field("footers", footers)?;
+        commit.serialize_field(
+            "issues",
+            &SETTINGS.issues.references(&self.commit.conventional),
+        )?;
         commit.end()
     }
 }
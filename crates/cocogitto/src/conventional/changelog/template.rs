@@ -36,6 +36,17 @@ const UNIFIED_REMOTE_TEMPLATE_NAME: &str = "unified_remote";
 const UNIFIED_FULL_HASH_TEMPLATE: &[u8] = include_bytes!("template/unified_full_hash.tera");
 const UNIFIED_FULL_HASH_TEMPLATE_NAME: &str = "unified_full_hash";
 
+const KEEP_A_CHANGELOG_TEMPLATE: &[u8] = include_bytes!("template/keepachangelog.tera");
+const KEEP_A_CHANGELOG_TEMPLATE_NAME: &str = "keepachangelog";
+const MONOREPO_KEEP_A_CHANGELOG_TEMPLATE: &[u8] =
+    include_bytes!("template/monorepo_keepachangelog.tera");
+const MONOREPO_KEEP_A_CHANGELOG_TEMPLATE_NAME: &str = "monorepo_keepachangelog";
+
+const ASCIIDOC_TEMPLATE: &[u8] = include_bytes!("template/asciidoc.tera");
+const ASCIIDOC_TEMPLATE_NAME: &str = "asciidoc";
+const MONOREPO_ASCIIDOC_TEMPLATE: &[u8] = include_bytes!("template/monorepo_asciidoc.tera");
+const MONOREPO_ASCIIDOC_TEMPLATE_NAME: &str = "monorepo_asciidoc";
+
 pub const MACROS_TEMPLATE: &[u8] = include_bytes!("template/macro/macros.tera");
 pub const MACROS_TEMPLATE_NAME: &str = "macros";
 
@@ -87,14 +98,25 @@ impl Template {
 
     pub fn render(&mut self, mut version: Release) -> Result<String, ChangelogError> {
         let tera = self.init_tera()?;
+        let syntax = self.kind.syntax();
+        let separator = match syntax {
+            ChangelogSyntax::Cocogitto => "\n- - -\n\n",
+            ChangelogSyntax::KeepAChangelog | ChangelogSyntax::Asciidoc => "\n",
+        };
+
         let mut release = self.render_release(&mut version, &tera)?;
         let mut version = version;
         while let Some(mut previous) = version.previous.map(|v| *v) {
-            release.push_str("\n- - -\n\n");
+            release.push_str(separator);
             release.push_str(self.render_release(&mut previous, &tera)?.as_str());
             version = previous;
         }
 
+        if syntax == ChangelogSyntax::KeepAChangelog {
+            let (body, links) = split_link_definitions(&release);
+            release = join_link_definitions(&body, &links);
+        }
+
         Ok(release)
     }
 
@@ -110,6 +132,10 @@ impl Template {
         tera.register_filter("upper_first", filters::upper_first_filter);
         tera.register_filter("unscoped", filters::unscoped);
         tera.register_filter("group_by_type", filters::group_by_type);
+        tera.register_filter(
+            "group_by_keepachangelog_section",
+            filters::group_by_keepachangelog_section,
+        );
         tera.check_macro_files()?;
 
         Ok(tera)
@@ -131,6 +157,47 @@ impl Template {
     }
 }
 
+/// Split keepachangelog link reference definitions (`[1.0.0]: https://...`) from the rest of the content.
+/// Definitions are deduplicated by label, the first one wins.
+pub(crate) fn split_link_definitions(content: &str) -> (String, Vec<(String, String)>) {
+    let mut body = String::with_capacity(content.len());
+    let mut links: Vec<(String, String)> = vec![];
+
+    for line in content.split_inclusive('\n') {
+        match link_definition_label(line) {
+            Some(label) => {
+                if !links.iter().any(|(existing, _)| existing == label) {
+                    links.push((label.to_string(), line.trim_end().to_string()));
+                }
+            }
+            None => body.push_str(line),
+        }
+    }
+
+    (body, links)
+}
+
+/// Append link reference definitions at the end of the content, separated with a blank line.
+pub(crate) fn join_link_definitions(body: &str, links: &[(String, String)]) -> String {
+    let mut content = body.trim_end().to_string();
+    content.push('\n');
+
+    if !links.is_empty() {
+        content.push('\n');
+        for (_, link) in links {
+            content.push_str(link);
+            content.push('\n');
+        }
+    }
+
+    content
+}
+
+fn link_definition_label(line: &str) -> Option<&str> {
+    let (label, target) = line.strip_prefix('[')?.split_once("]: ")?;
+    (!label.is_empty() && !label.contains(']') && !target.trim().is_empty()).then_some(label)
+}
+
 #[derive(Debug, Default, Eq, PartialEq)]
 pub enum TemplateKind {
     #[default]
@@ -146,9 +213,26 @@ pub enum TemplateKind {
     UnifiedDefault,
     UnifiedFullHash,
     UnifiedRemote,
+    KeepAChangelog,
+    MonorepoKeepAChangelog,
+    Asciidoc,
+    MonorepoAsciidoc,
     Custom(PathBuf),
 }
 
+/// The document format produced by a template, it drives how releases are separated
+/// and where new releases are inserted in an existing changelog file.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ChangelogSyntax {
+    /// Cocogitto markdown, releases are separated with `- - -`
+    #[default]
+    Cocogitto,
+    /// [Keep a Changelog](https://keepachangelog.com) markdown, with link references at the end of the file
+    KeepAChangelog,
+    /// AsciiDoc, releases are level 1 sections
+    Asciidoc,
+}
+
 impl TemplateKind {
     /// Returns either a predefined template or a custom template
     fn from_arg(value: &str, unified: bool) -> Result<Self, ChangelogError> {
@@ -173,6 +257,16 @@ impl TemplateKind {
             MONOREPO_DEFAULT_TEMPLATE_NAME => Ok(TemplateKind::MonorepoDefault),
             MONOREPO_REMOTE_TEMPLATE_NAME => Ok(TemplateKind::MonorepoRemote),
             MONOREPO_FULL_HASH_TEMPLATE_NAME => Ok(TemplateKind::MonorepoFullHash),
+
+            KEEP_A_CHANGELOG_TEMPLATE_NAME if !unified => Ok(TemplateKind::KeepAChangelog),
+            KEEP_A_CHANGELOG_TEMPLATE_NAME | MONOREPO_KEEP_A_CHANGELOG_TEMPLATE_NAME => {
+                Ok(TemplateKind::MonorepoKeepAChangelog)
+            }
+
+            ASCIIDOC_TEMPLATE_NAME if !unified => Ok(TemplateKind::Asciidoc),
+            ASCIIDOC_TEMPLATE_NAME | MONOREPO_ASCIIDOC_TEMPLATE_NAME => {
+                Ok(TemplateKind::MonorepoAsciidoc)
+            }
             path => {
                 let path = PathBuf::from(path);
                 if !path.exists() {
@@ -198,6 +292,10 @@ impl TemplateKind {
             TemplateKind::UnifiedDefault => Ok(UNIFIED_DEFAULT_TEMPLATE.to_vec()),
             TemplateKind::UnifiedRemote => Ok(UNIFIED_REMOTE_TEMPLATE.to_vec()),
             TemplateKind::UnifiedFullHash => Ok(UNIFIED_FULL_HASH_TEMPLATE.to_vec()),
+            TemplateKind::KeepAChangelog => Ok(KEEP_A_CHANGELOG_TEMPLATE.to_vec()),
+            TemplateKind::MonorepoKeepAChangelog => Ok(MONOREPO_KEEP_A_CHANGELOG_TEMPLATE.to_vec()),
+            TemplateKind::Asciidoc => Ok(ASCIIDOC_TEMPLATE.to_vec()),
+            TemplateKind::MonorepoAsciidoc => Ok(MONOREPO_ASCIIDOC_TEMPLATE.to_vec()),
             TemplateKind::Custom(path) => std::fs::read(path),
         }
     }
@@ -216,7 +314,21 @@ impl TemplateKind {
             TemplateKind::UnifiedDefault => UNIFIED_DEFAULT_TEMPLATE_NAME,
             TemplateKind::UnifiedRemote => UNIFIED_REMOTE_TEMPLATE_NAME,
             TemplateKind::UnifiedFullHash => UNIFIED_FULL_HASH_TEMPLATE_NAME,
+            TemplateKind::KeepAChangelog => KEEP_A_CHANGELOG_TEMPLATE_NAME,
+            TemplateKind::MonorepoKeepAChangelog => MONOREPO_KEEP_A_CHANGELOG_TEMPLATE_NAME,
+            TemplateKind::Asciidoc => ASCIIDOC_TEMPLATE_NAME,
+            TemplateKind::MonorepoAsciidoc => MONOREPO_ASCIIDOC_TEMPLATE_NAME,
             TemplateKind::Custom(_) => "custom_template",
         }
     }
+
+    pub(crate) const fn syntax(&self) -> ChangelogSyntax {
+        match self {
+            TemplateKind::KeepAChangelog | TemplateKind::MonorepoKeepAChangelog => {
+                ChangelogSyntax::KeepAChangelog
+            }
+            TemplateKind::Asciidoc | TemplateKind::MonorepoAsciidoc => ChangelogSyntax::Asciidoc,
+            _ => ChangelogSyntax::Cocogitto,
+        }
+    }
 }
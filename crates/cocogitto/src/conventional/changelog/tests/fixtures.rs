@@ -11,7 +11,7 @@ pub struct ReleaseFixture {
     pub release: Release,
 }
 
-impl<'a> ReleaseFixture {
+impl ReleaseFixture {
     pub fn builder() -> ReleaseFixture {
         ReleaseFixture {
             release: Release {
@@ -49,7 +49,7 @@ impl<'a> ReleaseFixture {
 
 impl Default for ReleaseFixture {
     fn default() -> Self {
-        return ReleaseFixture::builder()
+        ReleaseFixture::builder()
             .with_commit(
                 CommitFixture::default()
                     .with_scope("parser")
@@ -68,13 +68,13 @@ impl Default for ReleaseFixture {
                     .with_username("oknozor")
                     .with_message("fix parser implementation")
                     .with_footer("Co-authored-by", "Tom", Separator::Colon),
-            );
+            )
     }
 }
 
 impl ReleaseFixture {
     pub fn cocogitto() -> Self {
-        return ReleaseFixture::builder()
+        ReleaseFixture::builder()
             .with_commit(
                 CommitFixture::default()
                     .with_scope("parser")
@@ -99,7 +99,7 @@ impl ReleaseFixture {
                         Separator::Colon,
                     )
                     .with_commit_type(CommitType::Feature),
-            );
+            )
     }
 }
 
@@ -5,12 +5,17 @@ use itertools::Itertools;
 use pretty_assertions::assert_eq;
 use speculoos::prelude::*;
 
+use std::fs;
+
 use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::template::ChangelogSyntax;
 use crate::conventional::changelog::template::Template;
 use crate::conventional::changelog::tests::fixtures::{
     default_package_context, default_remote_context, monorepo_context, ReleaseFixture,
 };
+use crate::conventional::changelog::{remove_releases, Insertion, ReleaseType};
 use crate::git::repository::Repository;
+use crate::settings::InsertionFallback;
 
 macro_rules! assert_doc_eq {
     ($changelog:expr, $doc:literal) => {
@@ -298,3 +303,370 @@ changelog_test!(
     - (**parser**) fix parser implementation - ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) - [@oknozor](https://github.com/oknozor), Tom
     "
 );
+
+changelog_test!(
+    should_render_keepachangelog_template,
+    ReleaseFixture::default(),
+    Template::from_arg("keepachangelog", default_remote_context(), false)?,
+    "## [1.0.0] - 2015-09-05
+
+    ### Added
+    - **parser:** Implement the changelog generator ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) by [@oknozor](https://github.com/oknozor)
+    - Awesome feature ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe))
+
+    ### Fixed
+    - **parser:** Fix parser implementation ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) by [@oknozor](https://github.com/oknozor)
+
+    [Unreleased]: https://github.com/cocogitto/cocogitto/compare/1.0.0...HEAD
+    [1.0.0]: https://github.com/cocogitto/cocogitto/compare/0.1.0...1.0.0
+    "
+);
+
+changelog_test!(
+    should_render_asciidoc_template,
+    ReleaseFixture::default(),
+    Template::from_arg("asciidoc", None, false)?,
+    "== 1.0.0 - 2015-09-05
+
+    === Features
+
+    * (*parser*) implement the changelog generator - (17f7e23) - @oknozor
+    * awesome feature - (17f7e23) - Paul Delafosse
+
+    === Bug Fixes
+
+    * (*parser*) fix parser implementation - (17f7e23) - @oknozor
+    "
+);
+
+changelog_test!(
+    should_render_monorepo_keepachangelog_template,
+    ReleaseFixture::default(),
+    Template::from_arg("keepachangelog", None, true)?.with_context(monorepo_context()),
+    "## [1.0.0] - 2015-09-05
+
+    ### Packages
+    - one bumped to 0.1.0
+    - two bumped to 0.2.0
+
+    ### Added
+    - **parser:** Implement the changelog generator (17f7e23) by @oknozor
+    - Awesome feature (17f7e23)
+
+    ### Fixed
+    - **parser:** Fix parser implementation (17f7e23) by @oknozor
+    "
+);
+
+#[test]
+fn should_insert_keepachangelog_release_and_merge_links() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("CHANGELOG.md");
+    fs::write(
+        &path,
+        indoc! {"
+            # Changelog
+
+            ## [Unreleased]
+
+            ## [0.1.0] - 2015-01-01
+            ### Added
+            - Initial release
+
+            [Unreleased]: https://github.com/cocogitto/cocogitto/compare/0.1.0...HEAD
+            [0.1.0]: https://github.com/cocogitto/cocogitto/releases/tag/0.1.0
+        "},
+    )?;
+
+    let template = Template::from_arg("keepachangelog", default_remote_context(), false)?;
+    ReleaseFixture::default()
+        .build()
+        .write_to_file(&path, template, ReleaseType::Standard)?;
+
+    assert_eq!(
+        fs::read_to_string(&path)?,
+        indoc! {"
+            # Changelog
+
+            <!-- cog:releases -->
+
+            ## [1.0.0] - 2015-09-05
+
+            ### Added
+            - **parser:** Implement the changelog generator ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) by [@oknozor](https://github.com/oknozor)
+            - Awesome feature ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe))
+
+            ### Fixed
+            - **parser:** Fix parser implementation ([17f7e23](https://github.com/cocogitto/cocogitto/commit/17f7e23081db15e9318aeb37529b1d473cf41cbe)) by [@oknozor](https://github.com/oknozor)
+
+            ## [0.1.0] - 2015-01-01
+            ### Added
+            - Initial release
+
+            [Unreleased]: https://github.com/cocogitto/cocogitto/compare/1.0.0...HEAD
+            [1.0.0]: https://github.com/cocogitto/cocogitto/compare/0.1.0...1.0.0
+            [0.1.0]: https://github.com/cocogitto/cocogitto/releases/tag/0.1.0
+        "}
+    );
+    Ok(())
+}
+
+#[test]
+fn should_insert_asciidoc_release_in_new_file() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("CHANGELOG.adoc");
+
+    let template = Template::from_arg("asciidoc", None, false)?;
+    ReleaseFixture::default()
+        .build()
+        .write_to_file(&path, template, ReleaseType::Standard)?;
+
+    assert_eq!(
+        fs::read_to_string(&path)?,
+        indoc! {"
+            = Changelog
+
+            All notable changes to this project will be documented in this file.
+
+            == 1.0.0 - 2015-09-05
+
+            === Features
+
+            * (*parser*) implement the changelog generator - (17f7e23) - @oknozor
+            * awesome feature - (17f7e23) - Paul Delafosse
+
+            === Bug Fixes
+
+            * (*parser*) fix parser implementation - (17f7e23) - @oknozor
+        "}
+    );
+    Ok(())
+}
+
+fn insertion(syntax: ChangelogSyntax, fallback: InsertionFallback) -> Insertion<'static> {
+    Insertion {
+        syntax,
+        marker: "<!-- cog:releases -->",
+        fallback,
+    }
+}
+
+#[test]
+fn should_insert_release_after_marker() {
+    let changelog = indoc! {"
+        # Changelog
+
+        - - -
+        <!-- cog:releases -->
+        ## 0.1.0 - 2015-01-01
+        #### Features
+        - a release note with a horizontal rule
+
+        - - -
+
+        Changelog generated by [cocogitto](https://github.com/cocogitto/cocogitto).
+    "};
+
+    let result = insertion(ChangelogSyntax::Cocogitto, InsertionFallback::None)
+        .apply(Some(changelog.to_string()), "## 1.0.0 - 2015-09-05\n");
+
+    assert_that!(result).is_equal_to(Some((
+        indoc! {"
+            # Changelog
+
+            - - -
+            <!-- cog:releases -->
+            ## 1.0.0 - 2015-09-05
+
+            - - -
+            ## 0.1.0 - 2015-01-01
+            #### Features
+            - a release note with a horizontal rule
+
+            - - -
+
+            Changelog generated by [cocogitto](https://github.com/cocogitto/cocogitto).
+        "}
+        .to_string(),
+        false,
+    )));
+}
+
+#[test]
+fn should_migrate_separator_changelog_to_marker() {
+    let changelog = indoc! {"
+        # Changelog
+
+        - - -
+        ## 0.1.0 - 2015-01-01
+
+        - - -
+    "};
+
+    let result = insertion(ChangelogSyntax::Cocogitto, InsertionFallback::Auto)
+        .apply(Some(changelog.to_string()), "## 1.0.0 - 2015-09-05\n");
+
+    assert_that!(result).is_equal_to(Some((
+        indoc! {"
+            # Changelog
+
+            - - -
+            <!-- cog:releases -->
+            ## 1.0.0 - 2015-09-05
+
+            - - -
+            ## 0.1.0 - 2015-01-01
+
+            - - -
+        "}
+        .to_string(),
+        true,
+    )));
+}
+
+#[test]
+fn should_migrate_changelog_before_first_heading() {
+    let changelog = indoc! {"
+        # Changelog
+
+        ## 0.1.0 - 2015-01-01
+    "};
+
+    let result = insertion(ChangelogSyntax::Cocogitto, InsertionFallback::Heading)
+        .apply(Some(changelog.to_string()), "## 1.0.0 - 2015-09-05\n");
+
+    assert_that!(result).is_equal_to(Some((
+        indoc! {"
+            # Changelog
+
+            <!-- cog:releases -->
+            ## 1.0.0 - 2015-09-05
+
+            - - -
+            ## 0.1.0 - 2015-01-01
+        "}
+        .to_string(),
+        true,
+    )));
+}
+
+#[test]
+fn should_not_insert_without_marker_and_fallback() {
+    let changelog = "# Changelog\n\n- - -\n";
+
+    let result = insertion(ChangelogSyntax::Cocogitto, InsertionFallback::None)
+        .apply(Some(changelog.to_string()), "## 1.0.0 - 2015-09-05\n");
+
+    assert_that!(result).is_none();
+}
+
+#[test]
+fn should_not_find_separator_without_marker() {
+    let changelog = "# Changelog\n\n## 0.1.0 - 2015-01-01\n";
+
+    let result = insertion(ChangelogSyntax::Cocogitto, InsertionFallback::Separator)
+        .apply(Some(changelog.to_string()), "## 1.0.0 - 2015-09-05\n");
+
+    assert_that!(result).is_none();
+}
+
+#[test]
+fn should_create_changelog_with_marker() {
+    let result = insertion(ChangelogSyntax::Cocogitto, InsertionFallback::Auto)
+        .apply(None, "## 1.0.0 - 2015-09-05\n");
+
+    assert_that!(result).is_equal_to(Some((
+        indoc! {"
+            # Changelog
+            All notable changes to this project will be documented in this file. See [conventional commits](https://www.conventionalcommits.org/) for commit guidelines.
+
+            - - -
+            <!-- cog:releases -->
+            ## 1.0.0 - 2015-09-05
+
+            - - -
+
+            Changelog generated by [cocogitto](https://github.com/cocogitto/cocogitto)."}
+        .to_string(),
+        false,
+    )));
+}
+
+#[test]
+fn should_remove_promoted_pre_release_sections() {
+    let changelog = indoc! {"
+        # Changelog
+
+        - - -
+        <!-- cog:releases -->
+        ## [1.0.0-rc.2](https://github.com/o/r/compare/1.0.0-rc.1..1.0.0-rc.2) - 2015-09-05
+        #### Bug Fixes
+        - fix
+
+        - - -
+        ## 1.0.0-rc.1 - 2015-09-01
+        #### Features
+        - feature
+
+        - - -
+        ## 0.1.0 - 2015-01-01
+
+        - - -
+    "};
+
+    let tags = ["1.0.0-rc.1".to_string(), "1.0.0-rc.2".to_string()];
+    let result = remove_releases(changelog, ChangelogSyntax::Cocogitto, &tags);
+
+    assert_eq!(
+        result,
+        indoc! {"
+            # Changelog
+
+            - - -
+            <!-- cog:releases -->
+            ## 0.1.0 - 2015-01-01
+
+            - - -
+        "}
+    );
+}
+
+#[test]
+fn should_remove_keepachangelog_pre_release_sections_and_links() {
+    let changelog = indoc! {"
+        # Changelog
+
+        ## [1.0.0-rc.10] - 2015-09-05
+
+        ### Fixed
+        - fix
+
+        ## [1.0.0-rc.1] - 2015-09-01
+
+        ### Added
+        - feature
+
+        [1.0.0-rc.10]: https://github.com/o/r/compare/1.0.0-rc.1...1.0.0-rc.10
+        [1.0.0-rc.1]: https://github.com/o/r/releases/tag/1.0.0-rc.1
+    "};
+
+    let result = remove_releases(
+        changelog,
+        ChangelogSyntax::KeepAChangelog,
+        &["1.0.0-rc.1".to_string()],
+    );
+
+    assert_eq!(
+        result,
+        indoc! {"
+            # Changelog
+
+            ## [1.0.0-rc.10] - 2015-09-05
+
+            ### Fixed
+            - fix
+
+            [1.0.0-rc.10]: https://github.com/o/r/compare/1.0.0-rc.1...1.0.0-rc.10
+        "}
+    );
+}
@@ -1,13 +1,16 @@
 use std::cmp::Ordering;
 use std::fmt::{self, Formatter};
 
+use crate::conventional::bump_rule::BumpRule;
 pub use crate::conventional::error::ConventionalCommitError;
+use crate::conventional::version::Increment;
+use crate::settings::ScopePattern;
 use crate::{COMMITS_METADATA, SETTINGS};
 use chrono::{DateTime, NaiveDateTime, Utc};
 use colored::*;
 use conventional_commit_parser::commit::ConventionalCommit;
 use git2::Commit as Git2Commit;
-use log::info;
+use log::{info, warn};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Eq, PartialEq)]
@@ -36,6 +39,11 @@ pub struct CommitConfig {
     /// Specify a sort order attribute for this commit type.
     #[serde(default)]
     pub order: Option<u32>,
+    /// Scopes allowed for this commit type, overrides the global `scopes` setting.
+    /// Entries are glob patterns (e.g. `api-*`) or regular expressions wrapped in slashes (e.g. `/^v\d+$/`).
+    #[serde(default)]
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Option<Vec<String>>"))]
+    pub scopes: Option<Vec<ScopePattern>>,
 }
 
 impl CommitConfig {
@@ -46,6 +54,7 @@ impl CommitConfig {
             bump_minor: Some(false),
             bump_patch: Some(false),
             order: Some(0),
+            scopes: None,
         }
     }
 
@@ -60,6 +69,7 @@ impl CommitConfig {
             bump_minor: other.bump_minor.or(self.bump_minor),
             bump_patch: other.bump_patch.or(self.bump_patch),
             order: other.order.or(self.order),
+            scopes: other.scopes.or(self.scopes),
         }
     }
 
@@ -95,6 +105,7 @@ impl CommitConfig {
             && self.omit_from_changelog.is_none()
             && self.bump_minor.is_none()
             && self.changelog_title.is_none()
+            && self.scopes.is_none()
     }
 }
 
@@ -116,37 +127,13 @@ impl Commit {
         let conventional_commit = conventional_commit_parser::parse(message);
 
         match conventional_commit {
-            Ok(message) => {
-                let commit = Commit {
-                    oid,
-                    conventional: message,
-                    author,
-                    date,
-                };
-
-                if let (Some(scopes), Some(scope)) =
-                    (&SETTINGS.commit_scopes(), &commit.conventional.scope)
-                {
-                    if !scopes.contains(scope) {
-                        return Err(Box::new(ConventionalCommitError::CommitScopeNotDefined {
-                            oid: commit.oid.to_string(),
-                            summary: format_summary(&commit.conventional),
-                            scope: scope.to_string(),
-                            author: commit.author,
-                        }));
-                    }
-                }
-
-                match &COMMITS_METADATA.get(&commit.conventional.commit_type) {
-                    Some(_) => Ok(commit),
-                    None => Err(Box::new(ConventionalCommitError::CommitTypeNotAllowed {
-                        oid: commit.oid.to_string(),
-                        summary: format_summary(&commit.conventional),
-                        commit_type: commit.conventional.commit_type.to_string(),
-                        author: commit.author,
-                    })),
-                }
+            Ok(message) => Commit {
+                oid,
+                conventional: message,
+                author,
+                date,
             }
+            .validate(),
             Err(cause) => {
                 let message = git2_message.trim_end();
                 let summary = Commit::short_summary_from_str(message);
@@ -160,6 +147,79 @@ impl Commit {
         }
     }
 
+    /// Ensure the commit type is known and the commit scope is allowed for this type.
+    pub(crate) fn validate(self) -> Result<Self, Box<ConventionalCommitError>> {
+        // An empty `scopes` vector means no scopes are allowed, causing an `Err` to always be returned.
+        // If `scopes` is not provided, the scope check is skipped.
+        if let Some(scope) = &self.conventional.scope {
+            if !SETTINGS.is_scope_allowed(&self.conventional.commit_type, scope) {
+                return Err(Box::new(ConventionalCommitError::CommitScopeNotDefined {
+                    oid: self.oid.to_string(),
+                    summary: format_summary(&self.conventional),
+                    scope: scope.to_string(),
+                    author: self.author,
+                }));
+            }
+        }
+
+        match &COMMITS_METADATA.get(&self.conventional.commit_type) {
+            Some(_) => Ok(self),
+            None => Err(Box::new(ConventionalCommitError::CommitTypeNotAllowed {
+                oid: self.oid.to_string(),
+                summary: format_summary(&self.conventional),
+                commit_type: self.conventional.commit_type.to_string(),
+                author: self.author,
+            })),
+        }
+    }
+
+    /// Apply the `[lint]` rules to this commit, warning violations are logged
+    /// and error violations are returned as a [`ConventionalCommitError::LintError`].
+    pub(crate) fn lint(&self) -> Result<(), Box<ConventionalCommitError>> {
+        let (errors, warnings): (Vec<_>, Vec<_>) = SETTINGS
+            .lint
+            .lint(&self.conventional)
+            .into_iter()
+            .partition(|violation| violation.is_error());
+
+        for warning in warnings {
+            warn!(
+                "{} {} ({}): {}",
+                "Warning:".yellow().bold(),
+                self.shorthand(),
+                format_summary(&self.conventional),
+                warning
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Box::new(ConventionalCommitError::LintError {
+                oid: self.oid.clone(),
+                summary: format_summary(&self.conventional),
+                author: self.author.clone(),
+                violations: errors,
+            }))
+        }
+    }
+
+    /// Check that the commit references an issue if its type requires one in the `[issues]` settings.
+    pub(crate) fn check_issues(&self) -> Result<(), Box<ConventionalCommitError>> {
+        if SETTINGS.issues.is_required(&self.conventional)
+            && SETTINGS.issues.references(&self.conventional).is_empty()
+        {
+            return Err(Box::new(ConventionalCommitError::MissingIssueReference {
+                oid: self.oid.clone(),
+                summary: format_summary(&self.conventional),
+                commit_type: self.conventional.commit_type.to_string(),
+                author: self.author.clone(),
+            }));
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn shorthand(&self) -> &str {
         if self.oid != "not committed" {
             &self.oid[0..6]
@@ -174,6 +234,70 @@ impl Commit {
             .is_some_and(|config| config.omit_from_changelog() && !self.is_major_bump())
     }
 
+    /// The first bump rule matching this commit.
+    pub(crate) fn bump_rule(&self) -> Option<&'static BumpRule> {
+        SETTINGS
+            .bump_rules
+            .iter()
+            .find(|rule| rule.matches(&self.conventional))
+    }
+
+    /// Whether this commit triggers the given increment, bump rules taking precedence
+    /// over breaking changes and the commit type configuration.
+    pub(crate) fn triggers(&self, increment: Increment) -> bool {
+        if let Some(rule) = self.bump_rule() {
+            return rule.increment == increment;
+        }
+
+        match increment {
+            Increment::Major => self.is_major_bump(),
+            Increment::Minor => self.is_minor_bump(),
+            Increment::Patch => self.is_patch_bump(),
+            Increment::NoBump => false,
+        }
+    }
+
+    /// The version increment this commit would trigger on its own.
+    pub(crate) fn increment(&self) -> Increment {
+        if let Some(rule) = self.bump_rule() {
+            rule.increment
+        } else if self.is_major_bump() {
+            Increment::Major
+        } else if self.is_minor_bump() {
+            Increment::Minor
+        } else if self.is_patch_bump() {
+            Increment::Patch
+        } else {
+            Increment::NoBump
+        }
+    }
+
+    /// Why this commit triggers its increment, see [`Commit::increment`].
+    pub(crate) fn increment_reason(&self) -> String {
+        let commit_type = self.conventional.commit_type.as_ref();
+
+        if let Some(rule) = self.bump_rule() {
+            format!("bump rule {rule}")
+        } else if self.is_major_bump() {
+            let footer = self
+                .conventional
+                .footers
+                .iter()
+                .any(|footer| footer.is_breaking_change());
+            if footer {
+                "BREAKING CHANGE footer".to_string()
+            } else {
+                "breaking change `!`".to_string()
+            }
+        } else if self.is_minor_bump() {
+            format!("`{commit_type}` commits bump the minor version")
+        } else if self.is_patch_bump() {
+            format!("`{commit_type}` commits bump the patch version")
+        } else {
+            format!("`{commit_type}` commits do not bump the version")
+        }
+    }
+
     pub(crate) fn is_major_bump(&self) -> bool {
         self.conventional.is_breaking_change
     }
@@ -333,38 +457,18 @@ pub fn verify(
 
     match commit {
         Ok(commit) => {
-            // An empty `scopes` vector means no scopes are allowed, causing an `Err` to always be returned.
-            // If `scopes` is not provided, the scope check is skipped.
-            if let (Some(scopes), Some(scope)) = (&SETTINGS.commit_scopes(), &commit.scope) {
-                if !scopes.contains(scope) {
-                    return Err(Box::new(ConventionalCommitError::CommitScopeNotDefined {
-                        oid: "not committed".to_string(),
-                        summary: format_summary(&commit),
-                        scope: commit.scope.unwrap_or_else(|| "Unknown".to_string()),
-                        author: author.unwrap_or_else(|| "Unknown".to_string()),
-                    }));
-                }
-            }
-            match &COMMITS_METADATA.get(&commit.commit_type) {
-                Some(_) => {
-                    info!(
-                        "{}",
-                        Commit {
-                            oid: "not committed".to_string(),
-                            conventional: commit,
-                            date: Utc::now().naive_utc(),
-                            author: author.unwrap_or_else(|| "Unknown".to_string()),
-                        }
-                    );
-                    Ok(())
-                }
-                None => Err(Box::new(ConventionalCommitError::CommitTypeNotAllowed {
-                    oid: "not committed".to_string(),
-                    summary: format_summary(&commit),
-                    commit_type: commit.commit_type.to_string(),
-                    author: author.unwrap_or_else(|| "Unknown".to_string()),
-                })),
+            let commit = Commit {
+                oid: "not committed".to_string(),
+                conventional: commit,
+                date: Utc::now().naive_utc(),
+                author: author.unwrap_or_else(|| "Unknown".to_string()),
             }
+            .validate()?;
+
+            commit.lint()?;
+            commit.check_issues()?;
+            info!("{}", commit);
+            Ok(())
         }
         Err(err) => Err(Box::new(ConventionalCommitError::ParseError(err))),
     }
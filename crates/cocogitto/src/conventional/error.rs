@@ -1,8 +1,13 @@
+use crate::conventional::lint::LintViolation;
 use crate::git::error::{Git2Error, TagError};
+use crate::git::tag::Tag;
 use anyhow::anyhow;
 use colored::Colorize;
 use conventional_commit_parser::error::ParseError;
+use itertools::Itertools;
+use pest::error::LineColLocation;
 use serde::de::StdError;
+use serde::Serialize;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
@@ -26,9 +31,224 @@ pub enum ConventionalCommitError {
         scope: String,
         author: String,
     },
+    LintError {
+        oid: String,
+        summary: String,
+        author: String,
+        violations: Vec<LintViolation>,
+    },
+    MissingIssueReference {
+        oid: String,
+        summary: String,
+        commit_type: String,
+        author: String,
+    },
     ParseError(ParseError),
 }
 
+/// A plain, serializable view of a [`ConventionalCommitError`] used by
+/// machine-readable reports (`cog check --format json`, `cog verify --format json`).
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CommitErrorReport {
+    pub oid: Option<String>,
+    pub summary: Option<String>,
+    pub author: Option<String>,
+    pub kind: CommitErrorKind,
+    pub commit_type: Option<String>,
+    pub scope: Option<String>,
+    pub message: String,
+    pub span: Option<ErrorSpan>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<LintViolation>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitErrorKind {
+    CommitFormat,
+    CommitTypeNotAllowed,
+    CommitScopeNotDefined,
+    LintError,
+    MissingIssueReference,
+    ParseError,
+}
+
+impl CommitErrorKind {
+    /// A stable identifier for this kind of error, used as SARIF rule id.
+    pub fn rule_id(&self) -> &'static str {
+        match self {
+            CommitErrorKind::CommitFormat => "commit-format",
+            CommitErrorKind::CommitTypeNotAllowed => "commit-type-not-allowed",
+            CommitErrorKind::CommitScopeNotDefined => "commit-scope-not-defined",
+            CommitErrorKind::LintError => "lint",
+            CommitErrorKind::MissingIssueReference => "missing-issue-reference",
+            CommitErrorKind::ParseError => "parse-error",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            CommitErrorKind::CommitFormat => {
+                "Commit message does not follow the conventional commit specification"
+            }
+            CommitErrorKind::CommitTypeNotAllowed => "Commit type is not allowed",
+            CommitErrorKind::CommitScopeNotDefined => "Commit scope is not allowed",
+            CommitErrorKind::LintError => "Commit message violates a configured lint rule",
+            CommitErrorKind::MissingIssueReference => "Commit does not reference an issue",
+            CommitErrorKind::ParseError => {
+                "Message does not follow the conventional commit specification"
+            }
+        }
+    }
+}
+
+/// Location of a parse error in the commit message, lines and columns are 1-based.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct ErrorSpan {
+    pub start: ErrorPosition,
+    pub end: Option<ErrorPosition>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct ErrorPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl From<&ParseError> for ErrorSpan {
+    fn from(err: &ParseError) -> Self {
+        match err.inner.line_col {
+            LineColLocation::Pos((line, column)) => ErrorSpan {
+                start: ErrorPosition { line, column },
+                end: None,
+            },
+            LineColLocation::Span((line, column), (end_line, end_column)) => ErrorSpan {
+                start: ErrorPosition { line, column },
+                end: Some(ErrorPosition {
+                    line: end_line,
+                    column: end_column,
+                }),
+            },
+        }
+    }
+}
+
+impl ConventionalCommitError {
+    pub fn kind(&self) -> CommitErrorKind {
+        match self {
+            ConventionalCommitError::CommitFormat { .. } => CommitErrorKind::CommitFormat,
+            ConventionalCommitError::CommitTypeNotAllowed { .. } => {
+                CommitErrorKind::CommitTypeNotAllowed
+            }
+            ConventionalCommitError::CommitScopeNotDefined { .. } => {
+                CommitErrorKind::CommitScopeNotDefined
+            }
+            ConventionalCommitError::LintError { .. } => CommitErrorKind::LintError,
+            ConventionalCommitError::MissingIssueReference { .. } => {
+                CommitErrorKind::MissingIssueReference
+            }
+            ConventionalCommitError::ParseError(_) => CommitErrorKind::ParseError,
+        }
+    }
+
+    pub fn to_report(&self) -> CommitErrorReport {
+        let kind = self.kind();
+        match self {
+            ConventionalCommitError::CommitFormat {
+                oid,
+                summary,
+                author,
+                cause,
+            } => CommitErrorReport {
+                oid: Some(oid.clone()),
+                summary: Some(summary.clone()),
+                author: Some(author.clone()),
+                kind,
+                commit_type: None,
+                scope: None,
+                message: cause.to_string(),
+                span: Some(ErrorSpan::from(cause)),
+                violations: vec![],
+            },
+            ConventionalCommitError::CommitTypeNotAllowed {
+                oid,
+                summary,
+                commit_type,
+                author,
+            } => CommitErrorReport {
+                oid: Some(oid.clone()),
+                summary: Some(summary.clone()),
+                author: Some(author.clone()),
+                kind,
+                commit_type: Some(commit_type.clone()),
+                scope: None,
+                message: format!("Commit type `{commit_type}` not allowed"),
+                span: None,
+                violations: vec![],
+            },
+            ConventionalCommitError::CommitScopeNotDefined {
+                oid,
+                summary,
+                scope,
+                author,
+            } => CommitErrorReport {
+                oid: Some(oid.clone()),
+                summary: Some(summary.clone()),
+                author: Some(author.clone()),
+                kind,
+                commit_type: None,
+                scope: Some(scope.clone()),
+                message: format!("Commit scope `{scope}` not allowed"),
+                span: None,
+                violations: vec![],
+            },
+            ConventionalCommitError::LintError {
+                oid,
+                summary,
+                author,
+                violations,
+            } => CommitErrorReport {
+                oid: Some(oid.clone()),
+                summary: Some(summary.clone()),
+                author: Some(author.clone()),
+                kind,
+                commit_type: None,
+                scope: None,
+                message: violations.iter().map(|v| v.to_string()).join("\n"),
+                span: None,
+                violations: violations.clone(),
+            },
+            ConventionalCommitError::MissingIssueReference {
+                oid,
+                summary,
+                commit_type,
+                author,
+            } => CommitErrorReport {
+                oid: Some(oid.clone()),
+                summary: Some(summary.clone()),
+                author: Some(author.clone()),
+                kind,
+                commit_type: Some(commit_type.clone()),
+                scope: None,
+                message: format!("Commit type `{commit_type}` requires an issue reference"),
+                span: None,
+                violations: vec![],
+            },
+            ConventionalCommitError::ParseError(err) => CommitErrorReport {
+                oid: None,
+                summary: None,
+                author: None,
+                kind,
+                commit_type: None,
+                scope: None,
+                message: err.to_string(),
+                span: Some(ErrorSpan::from(err)),
+                violations: vec![],
+            },
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum BumpError {
     Git2Error(Git2Error),
@@ -36,6 +256,8 @@ pub enum BumpError {
     SemVerError(semver::Error),
     FmtError(fmt::Error),
     NoCommitFound,
+    AlreadyStable(semver::Version),
+    NoPreRelease(Box<Tag>),
 }
 
 impl Display for BumpError {
@@ -55,6 +277,13 @@ suggestion: Please see https://conventionalcommits.org/en/v1.0.0/#summary for mo
     Alternatively consider using `cog bump <--version <VERSION>|--auto|--major|--minor>`
 "#
             ),
+            BumpError::AlreadyStable(version) => writeln!(
+                f,
+                "\tcannot graduate {version} to 1.0.0, the version is already stable"
+            ),
+            BumpError::NoPreRelease(tag) => {
+                writeln!(f, "\tno pre-release greater than {tag} found to promote")
+            }
         }
     }
 }
@@ -152,6 +381,56 @@ impl Display for ConventionalCommitError {
                     scope = scope.red(),
                 )
             }
+            ConventionalCommitError::LintError {
+                oid,
+                summary,
+                author,
+                violations,
+            } => {
+                let error_header = "Errored commit: ".bold().red();
+                let author = format!("<{author}>").blue();
+                writeln!(
+                    f,
+                    "{}{} {}\n\t{message}'{summary}'",
+                    error_header,
+                    oid,
+                    author,
+                    message = "Commit message:".yellow().bold(),
+                    summary = summary.italic(),
+                )?;
+
+                for violation in violations {
+                    writeln!(
+                        f,
+                        "\t{cause}[{}] {}",
+                        violation.rule.to_string().red(),
+                        violation.message,
+                        cause = "Error: ".yellow().bold(),
+                    )?;
+                }
+
+                Ok(())
+            }
+            ConventionalCommitError::MissingIssueReference {
+                oid,
+                summary,
+                commit_type,
+                author,
+            } => {
+                let error_header = "Errored commit: ".bold().red();
+                let author = format!("<{author}>").blue();
+                writeln!(
+                    f,
+                    "{}{} {}\n\t{message}'{summary}'\n\t{cause}Commit type `{commit_type}` requires an issue reference",
+                    error_header,
+                    oid,
+                    author,
+                    message = "Commit message:".yellow().bold(),
+                    cause = "Error: ".yellow().bold(),
+                    summary = summary.italic(),
+                    commit_type = commit_type.red(),
+                )
+            }
             ConventionalCommitError::ParseError(err) => {
                 let err = anyhow!(err.clone());
                 writeln!(f, "{err:?}")
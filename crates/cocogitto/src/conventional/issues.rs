@@ -0,0 +1,183 @@
+use conventional_commit_parser::commit::ConventionalCommit;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// # IssuesSettings
+/// Issue and ticket references extraction and validation.
+///
+/// References are searched in the commit summary, body and footers and exposed in changelog
+/// templates as `commit.issues`.
+///
+///  **Example :**
+/// ```toml
+/// [issues]
+/// patterns = ["(?<id>[A-Z]+-[0-9]+)"]
+/// url = "https://jira.example.com/browse/{id}"
+/// required = ["feat", "fix"]
+/// ```
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields, default)]
+pub struct IssuesSettings {
+    /// Regular expressions matching issue references. The issue id is taken from the `id` named group,
+    /// the first capture group, or the whole match.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Vec<String>"))]
+    pub patterns: Vec<IssuePattern>,
+    /// Link to an issue, `{id}` is replaced with the issue id.
+    pub url: Option<String>,
+    /// Commit types that must reference at least one issue.
+    pub required: Vec<String>,
+}
+
+/// A regular expression from `issues.patterns`, compiled when loading the settings.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IssuePattern(Regex);
+
+impl IssuePattern {
+    /// Compile an issue reference pattern.
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        Regex::new(pattern)
+            .map(IssuePattern)
+            .map_err(|err| format!("invalid issue pattern '{pattern}': {err}"))
+    }
+}
+
+impl PartialEq for IssuePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for IssuePattern {}
+
+impl TryFrom<String> for IssuePattern {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        IssuePattern::parse(&pattern)
+    }
+}
+
+impl From<IssuePattern> for String {
+    fn from(pattern: IssuePattern) -> Self {
+        pattern.0.as_str().to_string()
+    }
+}
+
+/// An issue reference found in a commit message.
+#[derive(Debug, Clone, Serialize, Eq, PartialEq)]
+pub struct IssueReference {
+    /// The issue id, e.g. `COG-123` or `123`.
+    pub id: String,
+    /// The matched text, e.g. `#123`.
+    pub reference: String,
+    /// Link to the issue if an `url` is configured.
+    pub link: Option<String>,
+}
+
+impl IssuesSettings {
+    /// Extract the issue references from a commit message, in order of appearance and without duplicates.
+    pub fn references(&self, commit: &ConventionalCommit) -> Vec<IssueReference> {
+        let message = commit.to_string();
+        let mut references: Vec<(usize, IssueReference)> = vec![];
+
+        for IssuePattern(regex) in &self.patterns {
+            for captures in regex.captures_iter(&message) {
+                let reference = captures.get(0).expect("regex match");
+                let id = captures
+                    .name("id")
+                    .or_else(|| captures.get(1))
+                    .unwrap_or(reference)
+                    .as_str();
+
+                if references.iter().any(|(_, issue)| issue.id == id) {
+                    continue;
+                }
+
+                references.push((
+                    reference.start(),
+                    IssueReference {
+                        id: id.to_string(),
+                        reference: reference.as_str().to_string(),
+                        link: self.url.as_ref().map(|url| url.replace("{id}", id)),
+                    },
+                ));
+            }
+        }
+
+        references.sort_by_key(|(position, _)| *position);
+        references.into_iter().map(|(_, issue)| issue).collect()
+    }
+
+    /// Whether the commit type must reference an issue.
+    pub fn is_required(&self, commit: &ConventionalCommit) -> bool {
+        self.required
+            .iter()
+            .any(|commit_type| commit_type == commit.commit_type.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{IssuePattern, IssueReference, IssuesSettings};
+    use conventional_commit_parser::parse;
+    use speculoos::prelude::*;
+
+    fn jira_and_github() -> IssuesSettings {
+        IssuesSettings {
+            patterns: vec![
+                IssuePattern::parse("(?<id>[A-Z]+-[0-9]+)").unwrap(),
+                IssuePattern::parse("#([0-9]+)").unwrap(),
+            ],
+            url: Some("https://tracker.example.com/{id}".to_string()),
+            required: vec!["feat".to_string()],
+        }
+    }
+
+    #[test]
+    fn should_extract_references_in_order() {
+        let commit = parse("feat: add login for COG-12\n\nSee also #42\n\nRefs: COG-12").unwrap();
+
+        let references = jira_and_github().references(&commit);
+
+        assert_that!(references).is_equal_to(vec![
+            IssueReference {
+                id: "COG-12".to_string(),
+                reference: "COG-12".to_string(),
+                link: Some("https://tracker.example.com/COG-12".to_string()),
+            },
+            IssueReference {
+                id: "42".to_string(),
+                reference: "#42".to_string(),
+                link: Some("https://tracker.example.com/42".to_string()),
+            },
+        ]);
+    }
+
+    #[test]
+    fn should_extract_hash_separated_footer() {
+        let commit = parse("fix: crash\n\nCloses #7").unwrap();
+
+        let references = jira_and_github().references(&commit);
+
+        assert_that!(references
+            .iter()
+            .map(|issue| issue.id.as_str())
+            .collect::<Vec<_>>())
+        .is_equal_to(vec!["7"]);
+    }
+
+    #[test]
+    fn should_require_issue_for_configured_types() {
+        let settings = jira_and_github();
+
+        assert_that!(settings.is_required(&parse("feat: login").unwrap())).is_true();
+        assert_that!(settings.is_required(&parse("chore: login").unwrap())).is_false();
+    }
+
+    #[test]
+    fn should_reject_invalid_pattern() {
+        assert_that!(IssuePattern::parse("([A-Z")).is_err();
+    }
+}
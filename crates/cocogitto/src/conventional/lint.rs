@@ -0,0 +1,474 @@
+use std::fmt::{self, Display, Formatter};
+
+use conventional_commit_parser::commit::ConventionalCommit;
+use serde::{Deserialize, Serialize};
+
+/// # LintLevel
+/// Severity of a lint rule.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The rule is disabled.
+    Off,
+    /// A violation is reported but does not fail the command.
+    Warning,
+    /// A violation fails the command.
+    #[default]
+    Error,
+}
+
+/// # LintSettings
+/// Additional rules applied to commit messages by `cog verify`, `cog check`, `cog commit`
+/// and the `commit-msg` git hook. Every rule is disabled unless configured.
+///
+///  **Example :**
+/// ```toml
+/// [lint]
+/// header_max_length = { level = "error", max = 72 }
+/// summary_case = { level = "warning", case = "lower" }
+/// summary_trailing_period = { level = "error" }
+/// body_required = { level = "error", types = ["feat"] }
+/// body_max_line_length = { level = "warning", max = 100 }
+/// forbidden_words = { level = "error", words = ["WIP"] }
+///
+/// [[lint.required_footers]]
+/// level = "error"
+/// token = "Refs"
+/// types = ["fix"]
+/// ```
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields, default)]
+pub struct LintSettings {
+    /// Maximum number of characters in the commit header (`type(scope): summary`).
+    pub header_max_length: Option<LengthRule>,
+    /// Expected case of the first letter of the commit summary.
+    pub summary_case: Option<SummaryCaseRule>,
+    /// Forbid a trailing period at the end of the commit summary.
+    pub summary_trailing_period: Option<LevelRule>,
+    /// Require a commit body for the given commit types (all types if empty).
+    pub body_required: Option<TypesRule>,
+    /// Maximum number of characters in each line of the commit body.
+    pub body_max_line_length: Option<LengthRule>,
+    /// Require a footer token for the given commit types.
+    pub required_footers: Vec<FooterRule>,
+    /// Words that must not appear in the commit header or body.
+    pub forbidden_words: Option<ForbiddenWordsRule>,
+}
+
+/// # LevelRule
+/// A lint rule with no parameter.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LevelRule {
+    /// Severity of the rule.
+    #[serde(default)]
+    pub level: LintLevel,
+}
+
+/// # LengthRule
+/// A lint rule limiting a number of characters.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LengthRule {
+    /// Severity of the rule.
+    #[serde(default)]
+    pub level: LintLevel,
+    /// Maximum number of characters.
+    pub max: usize,
+}
+
+/// # SummaryCase
+/// Case of the first letter of a commit summary.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SummaryCase {
+    /// The summary must start with a lowercase letter.
+    Lower,
+    /// The summary must start with an uppercase letter.
+    Upper,
+}
+
+/// # SummaryCaseRule
+/// A lint rule enforcing the case of the commit summary.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SummaryCaseRule {
+    /// Severity of the rule.
+    #[serde(default)]
+    pub level: LintLevel,
+    /// Expected case.
+    pub case: SummaryCase,
+}
+
+/// # TypesRule
+/// A lint rule restricted to some commit types.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TypesRule {
+    /// Severity of the rule.
+    #[serde(default)]
+    pub level: LintLevel,
+    /// Commit types this rule applies to, all types if empty.
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+/// # FooterRule
+/// A lint rule requiring a footer token.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FooterRule {
+    /// Severity of the rule.
+    #[serde(default)]
+    pub level: LintLevel,
+    /// The required footer token (e.g. `Refs`), compared case-insensitively.
+    pub token: String,
+    /// Commit types this rule applies to, all types if empty.
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+/// # ForbiddenWordsRule
+/// A lint rule forbidding some words.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ForbiddenWordsRule {
+    /// Severity of the rule.
+    #[serde(default)]
+    pub level: LintLevel,
+    /// Forbidden words, compared case-insensitively.
+    pub words: Vec<String>,
+}
+
+/// Identifier of a lint rule, as reported in errors and machine-readable outputs.
+#[derive(Debug, Clone, Copy, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    HeaderMaxLength,
+    SummaryCase,
+    SummaryTrailingPeriod,
+    BodyRequired,
+    BodyMaxLineLength,
+    RequiredFooter,
+    ForbiddenWords,
+}
+
+impl AsRef<str> for LintRule {
+    fn as_ref(&self) -> &str {
+        match self {
+            LintRule::HeaderMaxLength => "header-max-length",
+            LintRule::SummaryCase => "summary-case",
+            LintRule::SummaryTrailingPeriod => "summary-trailing-period",
+            LintRule::BodyRequired => "body-required",
+            LintRule::BodyMaxLineLength => "body-max-line-length",
+            LintRule::RequiredFooter => "required-footer",
+            LintRule::ForbiddenWords => "forbidden-words",
+        }
+    }
+}
+
+impl Display for LintRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Eq, PartialEq)]
+pub struct LintViolation {
+    pub rule: LintRule,
+    pub level: LintLevel,
+    pub message: String,
+}
+
+impl LintViolation {
+    pub fn is_error(&self) -> bool {
+        self.level == LintLevel::Error
+    }
+}
+
+impl Display for LintViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.rule, self.message)
+    }
+}
+
+fn applies_to(types: &[String], commit: &ConventionalCommit) -> bool {
+    types.is_empty()
+        || types
+            .iter()
+            .any(|commit_type| commit_type == commit.commit_type.as_ref())
+}
+
+impl LintSettings {
+    /// Run every enabled rule against the given commit and return all violations,
+    /// whatever their level.
+    pub fn lint(&self, commit: &ConventionalCommit) -> Vec<LintViolation> {
+        let mut violations = vec![];
+        let mut report = |rule: LintRule, level: LintLevel, message: String| {
+            if level != LintLevel::Off {
+                violations.push(LintViolation {
+                    rule,
+                    level,
+                    message,
+                })
+            }
+        };
+
+        let message = commit.to_string();
+        let header = message.lines().next().unwrap_or_default();
+
+        if let Some(rule) = &self.header_max_length {
+            let length = header.chars().count();
+            if length > rule.max {
+                report(
+                    LintRule::HeaderMaxLength,
+                    rule.level,
+                    format!(
+                        "header is {length} characters long, maximum is {}",
+                        rule.max
+                    ),
+                );
+            }
+        }
+
+        if let Some(rule) = &self.summary_case {
+            if let Some(first) = commit.summary.chars().find(|c| c.is_alphabetic()) {
+                let (valid, expected) = match rule.case {
+                    SummaryCase::Lower => (!first.is_uppercase(), "lowercase"),
+                    SummaryCase::Upper => (!first.is_lowercase(), "uppercase"),
+                };
+
+                if !valid {
+                    report(
+                        LintRule::SummaryCase,
+                        rule.level,
+                        format!("summary must start with a {expected} letter"),
+                    );
+                }
+            }
+        }
+
+        if let Some(rule) = &self.summary_trailing_period {
+            if commit.summary.trim_end().ends_with('.') {
+                report(
+                    LintRule::SummaryTrailingPeriod,
+                    rule.level,
+                    "summary must not end with a period".to_string(),
+                );
+            }
+        }
+
+        if let Some(rule) = &self.body_required {
+            let has_body = commit
+                .body
+                .as_ref()
+                .is_some_and(|body| !body.trim().is_empty());
+
+            if applies_to(&rule.types, commit) && !has_body {
+                report(
+                    LintRule::BodyRequired,
+                    rule.level,
+                    format!("a body is required for `{}` commits", commit.commit_type),
+                );
+            }
+        }
+
+        if let (Some(rule), Some(body)) = (&self.body_max_line_length, &commit.body) {
+            for (idx, line) in body.lines().enumerate() {
+                let length = line.chars().count();
+                if length > rule.max {
+                    report(
+                        LintRule::BodyMaxLineLength,
+                        rule.level,
+                        format!(
+                            "body line {} is {length} characters long, maximum is {}",
+                            idx + 1,
+                            rule.max
+                        ),
+                    );
+                }
+            }
+        }
+
+        for rule in &self.required_footers {
+            let has_footer = commit
+                .footers
+                .iter()
+                .any(|footer| footer.token.eq_ignore_ascii_case(&rule.token));
+
+            if applies_to(&rule.types, commit) && !has_footer {
+                report(
+                    LintRule::RequiredFooter,
+                    rule.level,
+                    format!(
+                        "footer `{}` is required for `{}` commits",
+                        rule.token, commit.commit_type
+                    ),
+                );
+            }
+        }
+
+        if let Some(rule) = &self.forbidden_words {
+            let text = format!("{header}\n{}", commit.body.as_deref().unwrap_or_default());
+            let words: Vec<&str> = text
+                .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+                .filter(|word| !word.is_empty())
+                .collect();
+
+            for forbidden in &rule.words {
+                if words
+                    .iter()
+                    .any(|word| word.eq_ignore_ascii_case(forbidden))
+                {
+                    report(
+                        LintRule::ForbiddenWords,
+                        rule.level,
+                        format!("forbidden word `{forbidden}` found"),
+                    );
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use conventional_commit_parser::parse;
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    fn rules_of(violations: Vec<LintViolation>) -> Vec<LintRule> {
+        violations.into_iter().map(|v| v.rule).collect()
+    }
+
+    #[test]
+    fn should_not_lint_with_default_settings() {
+        // Arrange
+        let commit =
+            parse("feat: A very long summary that does not matter at all, no rules.").unwrap();
+
+        // Act
+        let violations = LintSettings::default().lint(&commit);
+
+        // Assert
+        assert_that!(violations).is_empty();
+    }
+
+    #[test]
+    fn should_report_header_and_summary_violations() {
+        // Arrange
+        let settings = LintSettings {
+            header_max_length: Some(LengthRule {
+                level: LintLevel::Error,
+                max: 20,
+            }),
+            summary_case: Some(SummaryCaseRule {
+                level: LintLevel::Warning,
+                case: SummaryCase::Lower,
+            }),
+            summary_trailing_period: Some(LevelRule {
+                level: LintLevel::Error,
+            }),
+            ..Default::default()
+        };
+        let commit = parse("feat(api): Add the login endpoint.").unwrap();
+
+        // Act
+        let violations = settings.lint(&commit);
+
+        // Assert
+        assert_that!(rules_of(violations)).is_equal_to(vec![
+            LintRule::HeaderMaxLength,
+            LintRule::SummaryCase,
+            LintRule::SummaryTrailingPeriod,
+        ]);
+    }
+
+    #[test]
+    fn should_skip_rules_turned_off() {
+        // Arrange
+        let settings = LintSettings {
+            summary_trailing_period: Some(LevelRule {
+                level: LintLevel::Off,
+            }),
+            ..Default::default()
+        };
+        let commit = parse("feat: add the login endpoint.").unwrap();
+
+        // Act
+        let violations = settings.lint(&commit);
+
+        // Assert
+        assert_that!(violations).is_empty();
+    }
+
+    #[test]
+    fn should_require_body_and_footer_for_configured_types() {
+        // Arrange
+        let settings = LintSettings {
+            body_required: Some(TypesRule {
+                level: LintLevel::Error,
+                types: vec!["feat".to_string()],
+            }),
+            required_footers: vec![FooterRule {
+                level: LintLevel::Error,
+                token: "Refs".to_string(),
+                types: vec!["fix".to_string()],
+            }],
+            ..Default::default()
+        };
+        let feat = parse("feat: add login").unwrap();
+        let fix = parse("fix: fix login\n\nrefs: #123").unwrap();
+        let chore = parse("chore: cleanup").unwrap();
+
+        // Act
+        let feat = settings.lint(&feat);
+        let fix = settings.lint(&fix);
+        let chore = settings.lint(&chore);
+
+        // Assert
+        assert_that!(rules_of(feat)).is_equal_to(vec![LintRule::BodyRequired]);
+        assert_that!(fix).is_empty();
+        assert_that!(chore).is_empty();
+    }
+
+    #[test]
+    fn should_report_body_line_length_and_forbidden_words() {
+        // Arrange
+        let settings = LintSettings {
+            body_max_line_length: Some(LengthRule {
+                level: LintLevel::Warning,
+                max: 10,
+            }),
+            forbidden_words: Some(ForbiddenWordsRule {
+                level: LintLevel::Error,
+                words: vec!["wip".to_string()],
+            }),
+            ..Default::default()
+        };
+        let commit = parse("feat: WIP login\n\nshort\na line that is too long").unwrap();
+
+        // Act
+        let violations = settings.lint(&commit);
+
+        // Assert
+        assert_that!(violations).has_length(2);
+        assert_that!(violations[0].message)
+            .is_equal_to("body line 2 is 23 characters long, maximum is 10".to_string());
+        assert_that!(violations[0].is_error()).is_false();
+        assert_that!(violations[1].rule).is_equal_to(LintRule::ForbiddenWords);
+        assert_that!(violations[1].is_error()).is_true();
+    }
+}
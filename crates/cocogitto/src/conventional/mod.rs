@@ -1,5 +1,9 @@
+pub mod branch;
 pub mod bump;
+pub mod bump_rule;
 pub mod changelog;
 pub mod commit;
 pub(crate) mod error;
+pub mod issues;
+pub mod lint;
 pub mod version;
@@ -1,4 +1,6 @@
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::fmt;
 
 #[derive(Debug, PartialEq, Eq, Default, Clone)]
 pub enum IncrementCommand {
@@ -11,16 +13,88 @@ pub enum IncrementCommand {
     AutoPackage(String),
     AutoMonoRepoGlobal(Option<Increment>),
     Manual(String),
+    /// Release 1.0.0 from a 0.y.z version
+    Graduate,
+    /// Release the latest pre-release as a stable version
+    Promote,
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// # Increment
+/// A version increment.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Increment {
+    /// Increment the major version
     Major,
+    /// Increment the minor version
     Minor,
+    /// Increment the patch version
     Patch,
+    /// Don't increment the version
+    #[serde(rename = "none")]
     NoBump,
 }
 
+/// A maintenance line such as `1.x` or `1.2.x`, the versions sharing a major
+/// or a major and minor version.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MaintenanceRange {
+    major: u64,
+    minor: Option<u64>,
+}
+
+impl MaintenanceRange {
+    /// Parse a `MAJOR.x` or `MAJOR.MINOR.x` range.
+    pub fn parse(range: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!("invalid maintenance range '{range}', expected 'MAJOR.x' or 'MAJOR.MINOR.x'")
+        };
+        let parts: Vec<&str> = range.split('.').collect();
+        let number = |part: &str| part.parse::<u64>().map_err(|_| invalid());
+
+        match parts.as_slice() {
+            [major, "x"] => Ok(MaintenanceRange {
+                major: number(major)?,
+                minor: None,
+            }),
+            [major, minor, "x"] => Ok(MaintenanceRange {
+                major: number(major)?,
+                minor: Some(number(minor)?),
+            }),
+            _ => Err(invalid()),
+        }
+    }
+
+    pub fn contains(&self, version: &semver::Version) -> bool {
+        version.major == self.major && self.minor.map_or(true, |minor| version.minor == minor)
+    }
+}
+
+impl fmt::Display for MaintenanceRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.minor {
+            Some(minor) => write!(f, "{}.{minor}.x", self.major),
+            None => write!(f, "{}.x", self.major),
+        }
+    }
+}
+
+impl TryFrom<String> for MaintenanceRange {
+    type Error = String;
+
+    fn try_from(range: String) -> Result<Self, Self::Error> {
+        MaintenanceRange::parse(&range)
+    }
+}
+
+impl From<MaintenanceRange> for String {
+    fn from(range: MaintenanceRange) -> Self {
+        range.to_string()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum PreCommand<'a> {
     /// Specify the exact pre-release version
@@ -40,6 +114,17 @@ impl From<Increment> for IncrementCommand {
     }
 }
 
+impl fmt::Display for Increment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Increment::Major => write!(f, "major"),
+            Increment::Minor => write!(f, "minor"),
+            Increment::Patch => write!(f, "patch"),
+            Increment::NoBump => write!(f, "none"),
+        }
+    }
+}
+
 impl Ord for Increment {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
@@ -66,5 +151,27 @@ impl PartialOrd for Increment {
 // Auto version tests resides in test/ dir since it rely on git log
 // To generate the version
 mod test {
-    // TODO
+    use semver::Version;
+    use speculoos::prelude::*;
+
+    use super::MaintenanceRange;
+
+    #[test]
+    fn should_parse_maintenance_ranges() {
+        let major = MaintenanceRange::parse("1.x").unwrap();
+        let minor = MaintenanceRange::parse("1.2.x").unwrap();
+
+        assert_that!(major.contains(&Version::new(1, 4, 2))).is_true();
+        assert_that!(major.contains(&Version::new(2, 0, 0))).is_false();
+        assert_that!(minor.contains(&Version::new(1, 2, 7))).is_true();
+        assert_that!(minor.contains(&Version::new(1, 3, 0))).is_false();
+        assert_that!(minor.to_string()).is_equal_to("1.2.x".to_string());
+    }
+
+    #[test]
+    fn should_reject_invalid_maintenance_ranges() {
+        assert_that!(MaintenanceRange::parse("1")).is_err();
+        assert_that!(MaintenanceRange::parse("1.2.3")).is_err();
+        assert_that!(MaintenanceRange::parse("v1.x")).is_err();
+    }
 }
@@ -2,22 +2,152 @@ use std::fmt::{self, Debug, Display, Formatter};
 
 use crate::git::oid::OidOf;
 
-use crate::conventional::error::ConventionalCommitError;
+use crate::conventional::error::{CommitErrorKind, CommitErrorReport, ConventionalCommitError};
 use colored::*;
+use serde::Serialize;
+use serde_json::json;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Output format of `cog check` and `cog verify` reports.
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Human-readable colored output
+    #[default]
+    Text,
+    /// A JSON document listing every errored commit
+    Json,
+    /// A SARIF 2.1.0 log, suitable for code scanning integrations
+    Sarif,
+}
 
 #[derive(Debug)]
-pub(crate) struct CogCheckReport {
-    pub from: OidOf,
+pub struct CogCheckReport {
+    pub from: Option<OidOf>,
     pub errors: Vec<ConventionalCommitError>,
 }
 
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    range: Option<String>,
+    error_count: usize,
+    errors: &'a [CommitErrorReport],
+}
+
+impl CogCheckReport {
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn range(&self) -> Option<String> {
+        self.from.as_ref().map(|from| format!("{from}..HEAD"))
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let errors: Vec<CommitErrorReport> = self.errors.iter().map(|e| e.to_report()).collect();
+        serde_json::to_string_pretty(&JsonReport {
+            range: self.range(),
+            error_count: errors.len(),
+            errors: &errors,
+        })
+    }
+
+    pub fn to_sarif(&self) -> serde_json::Result<String> {
+        let rules: Vec<_> = [
+            CommitErrorKind::CommitFormat,
+            CommitErrorKind::CommitTypeNotAllowed,
+            CommitErrorKind::CommitScopeNotDefined,
+            CommitErrorKind::LintError,
+            CommitErrorKind::MissingIssueReference,
+            CommitErrorKind::ParseError,
+        ]
+        .iter()
+        .map(|kind| {
+            json!({
+                "id": kind.rule_id(),
+                "shortDescription": { "text": kind.description() },
+            })
+        })
+        .collect();
+
+        let results: Vec<_> = self
+            .errors
+            .iter()
+            .flat_map(|err| {
+                let report = err.to_report();
+                if report.violations.is_empty() {
+                    return vec![(report.kind.rule_id().to_string(), report)];
+                }
+
+                // Emit one result per violated lint rule
+                report
+                    .violations
+                    .iter()
+                    .map(|violation| {
+                        let rule_id = format!("{}/{}", report.kind.rule_id(), violation.rule);
+                        let split = CommitErrorReport {
+                            message: violation.message.clone(),
+                            violations: vec![],
+                            ..report.clone()
+                        };
+                        (rule_id, split)
+                    })
+                    .collect()
+            })
+            .map(|(rule_id, report)| {
+                let mut result = json!({
+                    "ruleId": rule_id,
+                    "level": "error",
+                    "message": { "text": report.message },
+                    "properties": {
+                        "summary": report.summary,
+                        "author": report.author,
+                        "commitType": report.commit_type,
+                        "scope": report.scope,
+                        "span": report.span,
+                    },
+                });
+
+                if let Some(oid) = &report.oid {
+                    result["locations"] = json!([{
+                        "logicalLocations": [{ "fullyQualifiedName": oid, "kind": "commit" }]
+                    }]);
+                    result["partialFingerprints"] = json!({ "commitSha": oid });
+                }
+
+                result
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "cog",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": "https://docs.cocogitto.io",
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }]
+        }))
+    }
+}
+
 impl Display for CogCheckReport {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let header = format!(
-            "\nFound {} non compliant commits in {}..HEAD:\n",
-            self.errors.len(),
-            self.from
-        )
+        let header = match self.range() {
+            Some(range) => format!(
+                "\nFound {} non compliant commits in {}:\n",
+                self.errors.len(),
+                range
+            ),
+            None => format!("\nFound {} non compliant commits:\n", self.errors.len()),
+        }
         .red()
         .bold();
 
@@ -32,6 +162,57 @@ impl Display for CogCheckReport {
     }
 }
 
+/// A bump that failed after the bump commit, reported once the commit, tags
+/// and files have been rolled back.
+#[derive(Debug)]
+pub(crate) struct BumpRollbackError {
+    pub(crate) cause: String,
+    pub(crate) version: String,
+    pub(crate) head: Option<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) files: Vec<String>,
+    pub(crate) rollback_error: Option<String>,
+}
+
+impl Display for BumpRollbackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {} {}\n\tcause: {}",
+            "bump to".red(),
+            self.version,
+            "failed".red(),
+            self.cause
+        )?;
+
+        if let Some(err) = &self.rollback_error {
+            return write!(
+                f,
+                "\t{} the repository may be left in an intermediate state: {err}",
+                "rollback failed,".red()
+            );
+        }
+
+        writeln!(f, "\tThe bump has been rolled back:")?;
+        if let Some(head) = &self.head {
+            writeln!(f, "\t- HEAD reset to {head}")?;
+        }
+        if !self.tags.is_empty() {
+            writeln!(f, "\t- deleted tags: {}", self.tags.join(", "))?;
+        }
+        if !self.files.is_empty() {
+            writeln!(f, "\t- restored files: {}", self.files.join(", "))?;
+        }
+        write!(
+            f,
+            "\tOther changes made during the bump are left in the working tree,\n\
+        \tuse `--no-rollback` to keep the bump commit and tags on failure."
+        )
+    }
+}
+
+impl std::error::Error for BumpRollbackError {}
+
 // This is not meant to be unwrapped like other errors
 // just to emit a warning on hook failure
 pub(crate) struct BumpError {
@@ -64,19 +64,7 @@ impl Repository {
             .expect("Invalid UTF-8 commit message")
             .to_string();
 
-        let signature = if self.ssh_sign() {
-            let program = self.ssh_program();
-            let key = self.signing_key_path().ok();
-            ssh_sign_string(program, key, &commit_as_str)?
-        } else if self.x509_sign() {
-            let program = self.gpg_x509_program();
-            let user = sig.email().ok_or(Git2Error::MissingEmailInSignature)?;
-            x509_gitsign(program, user, &commit_as_str)?
-        } else {
-            let program = self.gpg_program();
-            let key = self.signing_key().ok();
-            gpg_sign_string(program, key, &commit_as_str)?
-        };
+        let signature = self.sign_string(self.signing_format(), sig, &commit_as_str)?;
 
         let oid = self
             .0
@@ -89,6 +77,42 @@ impl Repository {
     }
 }
 
+/// The signing backend, as configured with `gpg.format`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum SigningFormat {
+    OpenPgp,
+    Ssh,
+    X509,
+}
+
+impl Repository {
+    /// Sign a commit or tag object buffer, returning the armored signature.
+    pub(crate) fn sign_string(
+        &self,
+        format: SigningFormat,
+        sig: &Signature,
+        content: &str,
+    ) -> Result<String, Git2Error> {
+        match format {
+            SigningFormat::Ssh => {
+                let program = self.ssh_program();
+                let key = self.signing_key_path().ok();
+                ssh_sign_string(program, key, content)
+            }
+            SigningFormat::X509 => {
+                let program = self.gpg_x509_program();
+                let user = sig.email().ok_or(Git2Error::MissingEmailInSignature)?;
+                x509_gitsign(program, user, content)
+            }
+            SigningFormat::OpenPgp => {
+                let program = self.gpg_program();
+                let key = self.signing_key().ok();
+                gpg_sign_string(program, key, content)
+            }
+        }
+    }
+}
+
 // Clippy does not seem to be aware that `wait_with_output` is equivalent to `wait`
 #[allow(clippy::zombie_processes)]
 fn x509_gitsign(program: String, user: &str, content: &str) -> Result<String, Git2Error> {
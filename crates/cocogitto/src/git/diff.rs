@@ -1,5 +1,6 @@
 use crate::git::repository::Repository;
 use git2::{Diff, DiffOptions, Object};
+use std::path::PathBuf;
 
 impl Repository {
     pub(crate) fn get_diff(&self, include_untracked: bool) -> Option<Diff<'_>> {
@@ -27,6 +28,21 @@ impl Repository {
         }
     }
 
+    /// Paths of the files added to the index, relative to the repository root.
+    pub(crate) fn get_staged_paths(&self) -> Vec<PathBuf> {
+        let head = self.get_head();
+        let tree = head.as_ref().and_then(|head| head.as_tree());
+
+        self.0
+            .diff_tree_to_index(tree, None, None)
+            .map(|diff| {
+                diff.deltas()
+                    .filter_map(|delta| delta.new_file().path().map(PathBuf::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     fn get_head(&self) -> Option<Object<'_>> {
         self.tree_to_treeish(Some(&"HEAD".to_string()))
             .ok()
@@ -144,4 +160,31 @@ mod test {
         assert!(diffs.is_none());
         Ok(())
     }
+
+    #[sealed_test]
+    fn get_staged_paths_ignores_unstaged_files() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+
+        run_cmd!(
+            echo changes > file;
+            git add .;
+        )?;
+
+        repo.commit("first commit", false, false)?;
+
+        run_cmd!(
+            mkdir -p packages/one;
+            echo changes > packages/one/staged;
+            echo changes > unstaged;
+            git add packages/one/staged;
+        )?;
+
+        // Act
+        let paths = repo.get_staged_paths();
+
+        // Assert
+        assert_that!(paths).is_equal_to(vec![std::path::PathBuf::from("packages/one/staged")]);
+        Ok(())
+    }
 }
@@ -39,6 +39,14 @@ pub enum TagError {
         tag: String,
         err: semver::Error,
     },
+    CalVerError {
+        tag: String,
+        format: String,
+    },
+    TagFormatError {
+        tag: String,
+        format: String,
+    },
     InvalidPrefixError {
         prefix: String,
         tag: String,
@@ -83,6 +91,13 @@ impl TagError {
             err,
         }
     }
+    pub fn calver(tag: &str, format: &str) -> Self {
+        TagError::CalVerError {
+            tag: tag.to_string(),
+            format: format.to_string(),
+        }
+    }
+
     pub fn no_commit(tag: &str, err: git2::Error) -> Self {
         TagError::NotFound {
             tag: tag.to_string(),
@@ -184,6 +199,12 @@ impl Display for TagError {
                 writeln!(f, "tag `{tag}` is not SemVer compliant")?;
                 writeln!(f, "\tcause: {err}")
             }
+            TagError::CalVerError { tag, format } => {
+                writeln!(f, "tag `{tag}` does not match the calver format `{format}`")
+            }
+            TagError::TagFormatError { tag, format } => {
+                writeln!(f, "tag `{tag}` does not match the tag format `{format}`")
+            }
             TagError::InvalidPrefixError { prefix, tag } => {
                 writeln!(f, "Expected a tag with prefix {prefix}, got {tag}")
             }
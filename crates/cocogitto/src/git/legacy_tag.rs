@@ -0,0 +1,106 @@
+use regex::Regex;
+use semver::{Prerelease, Version};
+use serde::{Deserialize, Serialize};
+
+/// A pattern mapping the name of a legacy release tag such as `release-1.2` to a version,
+/// see the `legacy_tag_patterns` setting.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct LegacyTagPattern {
+    pattern: String,
+    regex: Regex,
+}
+
+impl LegacyTagPattern {
+    /// Parse a regex matching whole tag names, with a `major` capture group and optional
+    /// `minor`, `patch` and `pre` groups.
+    pub(crate) fn parse(pattern: &str) -> Result<Self, String> {
+        let regex = Regex::new(&format!("^(?:{pattern})$"))
+            .map_err(|err| format!("invalid legacy tag pattern '{pattern}': {err}"))?;
+
+        if !regex.capture_names().flatten().any(|name| name == "major") {
+            return Err(format!(
+                "invalid legacy tag pattern '{pattern}', a `major` capture group is required"
+            ));
+        }
+
+        Ok(LegacyTagPattern {
+            pattern: pattern.to_string(),
+            regex,
+        })
+    }
+
+    /// The version of a legacy tag, missing `minor` and `patch` default to zero.
+    pub(crate) fn version(&self, raw: &str) -> Option<Version> {
+        let captures = self.regex.captures(raw)?;
+        let number = |name: &str| match captures.name(name) {
+            Some(number) => number.as_str().parse::<u64>().ok(),
+            None => Some(0),
+        };
+
+        let mut version = Version::new(number("major")?, number("minor")?, number("patch")?);
+        if let Some(pre) = captures.name("pre") {
+            version.pre = Prerelease::new(pre.as_str()).ok()?;
+        }
+
+        Some(version)
+    }
+}
+
+impl PartialEq for LegacyTagPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for LegacyTagPattern {}
+
+impl TryFrom<String> for LegacyTagPattern {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        LegacyTagPattern::parse(&pattern)
+    }
+}
+
+impl From<LegacyTagPattern> for String {
+    fn from(pattern: LegacyTagPattern) -> Self {
+        pattern.pattern
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use semver::Version;
+    use speculoos::prelude::*;
+
+    use super::LegacyTagPattern;
+
+    #[test]
+    fn should_map_legacy_tags_to_versions() {
+        let release = LegacyTagPattern::parse(r"release-(?P<major>\d+)\.(?P<minor>\d+)").unwrap();
+        let rel = LegacyTagPattern::parse(
+            r"REL_(?P<major>\d+)_(?P<minor>\d+)_(?P<patch>\d+)(?:_(?P<pre>\w+))?",
+        )
+        .unwrap();
+
+        assert_that!(release.version("release-1.2")).is_equal_to(Some(Version::new(1, 2, 0)));
+        assert_that!(rel.version("REL_2_0_0")).is_equal_to(Some(Version::new(2, 0, 0)));
+        assert_that!(rel.version("REL_2_1_0_rc1"))
+            .is_equal_to(Some(Version::parse("2.1.0-rc1").unwrap()));
+    }
+
+    #[test]
+    fn should_match_whole_tag_names() {
+        let release = LegacyTagPattern::parse(r"release-(?P<major>\d+)\.(?P<minor>\d+)").unwrap();
+
+        assert_that!(release.version("release-1.2-hotfix")).is_none();
+        assert_that!(release.version("old-release-1.2")).is_none();
+    }
+
+    #[test]
+    fn should_require_major_group() {
+        assert_that!(LegacyTagPattern::parse(r"release-(\d+)")).is_err();
+        assert_that!(LegacyTagPattern::parse(r"release-(?P<major>\d+")).is_err();
+    }
+}
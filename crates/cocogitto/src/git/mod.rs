@@ -2,6 +2,7 @@ pub mod commit;
 pub mod diff;
 pub(crate) mod error;
 pub mod hook;
+pub mod legacy_tag;
 pub mod monorepo;
 pub mod oid;
 pub mod repository;
@@ -9,3 +10,5 @@ pub mod rev;
 pub mod stash;
 pub mod status;
 pub mod tag;
+pub mod tag_format;
+pub(crate) mod version_source;
@@ -4,7 +4,10 @@ use std::{
     path::PathBuf,
 };
 
+use crate::conventional::version::MaintenanceRange;
+use crate::git::commit::SigningFormat;
 use crate::git::error::Git2Error;
+use crate::SETTINGS;
 use git2::{
     Commit as Git2Commit, IndexAddOption, Object, ObjectType, Oid, Repository as Git2Repository,
 };
@@ -27,6 +30,21 @@ impl Repository {
         config.get_bool("commit.gpgSign").unwrap_or(false)
     }
 
+    pub(crate) fn tag_gpg_sign(&self) -> bool {
+        let config = self.0.config().expect("failed to retrieve gitconfig");
+        config.get_bool("tag.gpgSign").unwrap_or(false)
+    }
+
+    /// The signing backend configured with `gpg.format`, OpenPGP by default.
+    pub(crate) fn signing_format(&self) -> SigningFormat {
+        let config = self.0.config().expect("failed to retrieve gitconfig");
+        match config.get_string("gpg.format").map(|s| s.to_lowercase()) {
+            Ok(format) if format == "ssh" => SigningFormat::Ssh,
+            Ok(format) if format == "x509" => SigningFormat::X509,
+            _ => SigningFormat::OpenPgp,
+        }
+    }
+
     pub(crate) fn gpg_x509_program(&self) -> String {
         let config = self.0.config().expect("failed to retrieve gitconfig");
         config
@@ -41,17 +59,6 @@ impl Repository {
             .unwrap_or("gpg".to_string())
     }
 
-    pub(crate) fn ssh_sign(&self) -> bool {
-        let config = self.0.config().expect("failed to retrieve gitconfig");
-        if config.get_bool("commit.gpgSign").is_err() {
-            return false;
-        }
-
-        config
-            .get_string("gpg.format")
-            .is_ok_and(|s| s.to_lowercase() == "ssh")
-    }
-
     pub(crate) fn ssh_program(&self) -> String {
         let config = self.0.config().expect("failed to retrieve gitconfig");
         config
@@ -59,17 +66,6 @@ impl Repository {
             .unwrap_or("ssh-keygen".to_string())
     }
 
-    pub(crate) fn x509_sign(&self) -> bool {
-        let config = self.0.config().expect("failed to retrieve gitconfig");
-        if config.get_bool("commit.gpgSign").is_err() {
-            return false;
-        }
-
-        config
-            .get_string("gpg.format")
-            .is_ok_and(|s| s.to_lowercase() == "x509")
-    }
-
     pub(crate) fn init<S: AsRef<Path> + ?Sized>(path: &S) -> Result<Repository, Git2Error> {
         let repository =
             Git2Repository::init(path).map_err(Git2Error::FailedToInitializeRepository)?;
@@ -105,6 +101,68 @@ impl Repository {
         self.get_head_commit().map(|commit| commit.id())
     }
 
+    /// Move HEAD and the current branch to the given commit and reset the index,
+    /// the working tree is left untouched.
+    pub(crate) fn reset_mixed(&self, oid: Oid) -> Result<(), Git2Error> {
+        let commit = self.0.find_object(oid, Some(ObjectType::Commit))?;
+        self.0
+            .reset(&commit, git2::ResetType::Mixed, None)
+            .map_err(Git2Error::from)
+    }
+
+    /// Restore a file to its content in the given commit, the file is removed
+    /// if it does not exist in that commit. `path` is relative to the repository root.
+    pub(crate) fn restore_file(&self, oid: Oid, path: &Path) -> Result<(), Git2Error> {
+        let tree = self.0.find_commit(oid)?.tree()?;
+        let file = self.0.workdir().unwrap_or(Path::new("")).join(path);
+        match tree.get_path(path) {
+            Ok(entry) => {
+                let blob = entry.to_object(&self.0)?.peel_to_blob()?;
+                std::fs::write(file, blob.content())?;
+            }
+            Err(err) if err.code() == git2::ErrorCode::NotFound => {
+                if file.exists() {
+                    std::fs::remove_file(file)?;
+                }
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        Ok(())
+    }
+
+    /// The paths staged in the index, relative to the repository root.
+    pub(crate) fn staged_paths(&self) -> Result<Vec<PathBuf>, Git2Error> {
+        let head = self.get_head_commit()?.tree()?;
+        let diff = self.0.diff_tree_to_index(Some(&head), None, None)?;
+
+        Ok(diff
+            .deltas()
+            .filter_map(|delta| delta.new_file().path().or(delta.old_file().path()))
+            .map(Path::to_path_buf)
+            .collect())
+    }
+
+    /// A path relative to the current directory, made relative to the repository root.
+    pub(crate) fn relative_path(&self, path: &Path) -> PathBuf {
+        let workdir = self
+            .0
+            .workdir()
+            .and_then(|workdir| workdir.canonicalize().ok());
+        let current_dir = std::env::current_dir()
+            .and_then(|dir| dir.canonicalize())
+            .ok();
+
+        match workdir.zip(current_dir) {
+            Some((workdir, current_dir)) => current_dir
+                .join(path)
+                .strip_prefix(&workdir)
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|_| path.to_path_buf()),
+            None => path.to_path_buf(),
+        }
+    }
+
     pub(crate) fn get_head_commit(&self) -> Result<Git2Commit<'_>, Git2Error> {
         let head_ref = self.0.head();
         match head_ref {
@@ -129,6 +187,12 @@ impl Repository {
             .and_then(|head| head.shorthand().map(|shorthand| shorthand.to_string()))
     }
 
+    /// The maintenance range of the release branch matching the current branch, if any.
+    pub(crate) fn maintenance_range(&self) -> Option<MaintenanceRange> {
+        let branch = self.get_branch_shorthand()?;
+        SETTINGS.release_branch(&branch)?.range
+    }
+
     pub(crate) fn get_author(&self) -> Result<String, Git2Error> {
         self.0
             .signature()?
@@ -167,6 +231,7 @@ mod test {
     use sealed_test::prelude::*;
     use speculoos::prelude::*;
 
+    use crate::git::commit::SigningFormat;
     use crate::git::repository::Repository;
     use crate::test_helpers::git_init_no_gpg;
 
@@ -198,6 +263,25 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn signing_format_follows_gpg_format() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        let default = repo.signing_format();
+
+        // Act
+        run_cmd!(git config --local gpg.format SSH;)?;
+        let ssh = repo.signing_format();
+        run_cmd!(git config --local gpg.format x509;)?;
+        let x509 = repo.signing_format();
+
+        // Assert
+        assert_that!(default).is_equal_to(SigningFormat::OpenPgp);
+        assert_that!(ssh).is_equal_to(SigningFormat::Ssh);
+        assert_that!(x509).is_equal_to(SigningFormat::X509);
+        Ok(())
+    }
+
     #[sealed_test]
     fn get_repo_head_oid_ok() -> Result<()> {
         // Arrange
@@ -63,6 +63,13 @@ fn build_cache(
         }
     }
 
+    // on a maintenance branch, ignore the non package tags outside of the maintenance range
+    let maintenance_range = repo.maintenance_range();
+    let tags_from_head = tags_from_head.into_iter().filter(|tag| {
+        tag.package.is_some()
+            || maintenance_range.map_or(true, |range| range.contains(&tag.version))
+    });
+
     // actually add tags to cache
     for tag in tags_from_head {
         if let Some(oid) = &tag.oid {
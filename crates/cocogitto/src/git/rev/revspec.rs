@@ -136,6 +136,7 @@ mod test {
             prefix: None,
             version: Version::new(1, 0, 0),
             oid: Some(Oid::from_str(&commit_oid)?),
+            legacy_name: None,
         }));
 
         Ok(())
@@ -2,13 +2,16 @@ use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Formatter;
 
-use git2::Oid;
+use git2::{ObjectType, Oid};
+use itertools::Itertools;
 use semver::Version;
 
 use crate::conventional::version::Increment;
 use crate::git::error::{Git2Error, TagError};
 use crate::git::oid::OidOf;
 use crate::git::repository::Repository;
+use crate::git::tag_format::TagFormat;
+use crate::version_scheme::VersionScheme;
 use crate::SETTINGS;
 
 #[derive(Copy, Clone, Debug, Default)]
@@ -56,6 +59,11 @@ impl Repository {
             return Err(Git2Error::ChangesNeedToBeCommitted(statuses));
         }
 
+        if self.tag_gpg_sign() {
+            // Like git, signing requires an annotated tag
+            return self.create_signed_tag(tag, &tag.to_string());
+        }
+
         let head = self.get_head_commit().unwrap();
         self.0
             .tag_lightweight(&tag.to_string(), &head.into_object(), false)
@@ -74,6 +82,10 @@ impl Repository {
             return Err(Git2Error::ChangesNeedToBeCommitted(statuses));
         }
 
+        if self.tag_gpg_sign() {
+            return self.create_signed_tag(tag, msg);
+        }
+
         let head = self.get_head_commit().unwrap();
         let sig = self.0.signature()?;
         self.0
@@ -82,6 +94,43 @@ impl Repository {
             .map_err(Git2Error::from)
     }
 
+    /// Create an annotated tag signed with the backend configured by `gpg.format`.
+    /// git2 cannot sign tags, so the tag object is written to the object database directly.
+    fn create_signed_tag(&self, tag: &Tag, msg: &str) -> Result<(), Git2Error> {
+        let head = self.get_head_commit()?;
+        let sig = self.0.signature()?;
+        let name = tag.to_string();
+
+        let time = sig.when();
+        let offset = time.offset_minutes();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let tagger = format!(
+            "{sig} {} {sign}{:02}{:02}",
+            time.seconds(),
+            offset.abs() / 60,
+            offset.abs() % 60
+        );
+
+        let content = format!(
+            "object {}\ntype commit\ntag {name}\ntagger {tagger}\n\n{}\n",
+            head.id(),
+            msg.trim_end()
+        );
+
+        let signature = self.sign_string(self.signing_format(), &sig, &content)?;
+        let odb = self.0.odb()?;
+        let oid = odb.write(ObjectType::Tag, format!("{content}{signature}").as_bytes())?;
+
+        self.0
+            .reference(&format!("refs/tags/{name}"), oid, false, "cog: signed tag")
+            .map(|_| ())
+            .map_err(Git2Error::from)
+    }
+
+    pub(crate) fn delete_tag(&self, tag: &Tag) -> Result<(), Git2Error> {
+        self.0.tag_delete(&tag.to_string()).map_err(Git2Error::from)
+    }
+
     /// Get the latest tag, will ignore package tag if on a monorepo
     pub(crate) fn get_latest_tag(&self, options: TagLookUpOptions) -> Result<Tag, TagError> {
         let tags: Vec<Tag> = self.tag_lookup(options)?;
@@ -162,6 +211,8 @@ pub struct Tag {
     pub version: Version,
     /// Oid of the commit pointed to by the tag
     pub oid: Option<Oid>,
+    /// Original name of a tag matching one of the `legacy_tag_patterns`
+    pub legacy_name: Option<String>,
 }
 
 impl Ord for Tag {
@@ -195,6 +246,7 @@ impl Tag {
         let mut copy_without_prefix = self.clone();
         copy_without_prefix.package = None;
         copy_without_prefix.prefix = None;
+        copy_without_prefix.legacy_name = None;
         copy_without_prefix
     }
 
@@ -212,6 +264,7 @@ impl Tag {
             prefix: SETTINGS.tag_prefix.clone(),
             version,
             oid: None,
+            legacy_name: None,
         }
     }
 
@@ -220,6 +273,14 @@ impl Tag {
     }
 
     pub fn from_str(raw: &str, oid: Option<Oid>) -> Result<Tag, TagError> {
+        Tag::parse(raw, oid).or_else(|err| Tag::from_legacy_patterns(raw, oid).ok_or(err))
+    }
+
+    fn parse(raw: &str, oid: Option<Oid>) -> Result<Tag, TagError> {
+        if let Some(format) = &SETTINGS.tag_format {
+            return Tag::from_tag_format(raw, oid, format);
+        }
+
         let prefix = SETTINGS.tag_prefix.as_ref();
 
         let package_tag: Option<Tag> = SETTINGS
@@ -238,12 +299,13 @@ impl Tag {
                             .and_then(|prefix| remains.strip_prefix(prefix))
                             .unwrap_or(remains)
                     })
-                    .and_then(|version| Version::parse(version).ok())
+                    .and_then(|version| SETTINGS.version_scheme(Some(package_name)).parse(version))
                     .map(|version| Tag {
                         package: Some(package_name.to_string()),
                         prefix: SETTINGS.tag_prefix.clone(),
                         version,
                         oid,
+                        legacy_name: None,
                     })
             })
             .next();
@@ -255,17 +317,105 @@ impl Tag {
                 .and_then(|prefix| raw.strip_prefix(prefix))
                 .unwrap_or(raw);
 
-            let version = Version::parse(version).map_err(|err| TagError::semver(raw, err))?;
+            let version = match SETTINGS.version_scheme(None) {
+                VersionScheme::SemVer => {
+                    Version::parse(version).map_err(|err| TagError::semver(raw, err))?
+                }
+                VersionScheme::CalVer(format) => format
+                    .parse(version)
+                    .ok_or_else(|| TagError::calver(raw, format.as_str()))?,
+            };
 
             Ok(Tag {
                 package: None,
                 prefix: prefix.cloned(),
                 version,
                 oid,
+                legacy_name: None,
             })
         }
     }
 
+    /// Parse a tag with the `tag_format` template. Packages are matched longest name first,
+    /// so that a package name sharing a prefix with another one is never mistaken for it.
+    fn from_tag_format(raw: &str, oid: Option<Oid>, format: &TagFormat) -> Result<Tag, TagError> {
+        let packages = SETTINGS
+            .monorepo
+            .as_ref()
+            .map(|m| m.packages.keys())
+            .unwrap_or_default()
+            .sorted_by_key(|package_name| std::cmp::Reverse(package_name.len()));
+
+        let package_tag = packages
+            .filter_map(|package_name| {
+                let version = format.version_part(raw, Some(package_name))?;
+                let version = SETTINGS.version_scheme(Some(package_name)).parse(version)?;
+                Some((package_name, version))
+            })
+            .next();
+
+        if let Some((package_name, version)) = package_tag {
+            return Ok(Tag {
+                package: Some(package_name.to_string()),
+                prefix: None,
+                version,
+                oid,
+                legacy_name: None,
+            });
+        }
+
+        let version = format
+            .version_part(raw, None)
+            .ok_or_else(|| TagError::TagFormatError {
+                tag: raw.to_string(),
+                format: format.as_str().to_string(),
+            })?;
+
+        let version = match SETTINGS.version_scheme(None) {
+            VersionScheme::SemVer => {
+                Version::parse(version).map_err(|err| TagError::semver(raw, err))?
+            }
+            VersionScheme::CalVer(calver) => calver
+                .parse(version)
+                .ok_or_else(|| TagError::calver(raw, calver.as_str()))?,
+        };
+
+        Ok(Tag {
+            package: None,
+            prefix: None,
+            version,
+            oid,
+            legacy_name: None,
+        })
+    }
+
+    /// Parse a tag matching one of the `legacy_tag_patterns`, keeping its original name.
+    fn from_legacy_patterns(raw: &str, oid: Option<Oid>) -> Option<Tag> {
+        let version = SETTINGS
+            .legacy_tag_patterns
+            .iter()
+            .find_map(|pattern| pattern.version(raw))?;
+
+        Some(Tag {
+            package: None,
+            prefix: if SETTINGS.tag_format.is_some() {
+                None
+            } else {
+                SETTINGS.tag_prefix.clone()
+            },
+            version,
+            oid,
+            legacy_name: Some(raw.to_string()),
+        })
+    }
+
+    /// The version formatted with the version scheme of the tag package.
+    pub fn formatted_version(&self) -> String {
+        SETTINGS
+            .version_scheme(self.package.as_deref())
+            .format(&self.version)
+    }
+
     pub(crate) fn is_zero(&self) -> bool {
         self.version == Version::new(0, 0, 0)
     }
@@ -285,8 +435,14 @@ impl Tag {
 
 impl fmt::Display for Tag {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let version = self.version.to_string();
-        if let Some((package, prefix)) = self.package.as_ref().zip(self.prefix.as_ref()) {
+        if let Some(legacy_name) = &self.legacy_name {
+            return write!(f, "{legacy_name}");
+        }
+
+        let version = self.formatted_version();
+        if let Some(format) = &SETTINGS.tag_format {
+            write!(f, "{}", format.format(self.package.as_deref(), &version))
+        } else if let Some((package, prefix)) = self.package.as_ref().zip(self.prefix.as_ref()) {
             let separator = SETTINGS.monorepo_separator().unwrap_or_else(||
                 panic!("Found a tag with monorepo package prefix but there are no packages in cog.toml")
             );
@@ -312,7 +468,7 @@ mod test {
     use std::path::PathBuf;
 
     use anyhow::Result;
-    use cmd_lib::run_cmd;
+    use cmd_lib::{run_cmd, run_fun};
     use sealed_test::prelude::*;
     use semver::Version;
     use speculoos::prelude::*;
@@ -404,6 +560,7 @@ mod test {
             prefix: None,
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         });
 
         Ok(())
@@ -428,6 +585,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         });
 
         Ok(())
@@ -457,6 +615,7 @@ mod test {
             prefix: None,
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         });
 
         Ok(())
@@ -487,6 +646,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         });
 
         Ok(())
@@ -518,6 +678,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         });
 
         Ok(())
@@ -682,4 +843,60 @@ mod test {
         assert_that!(tag.to_string()).is_equal_to("lunatic-timer-api-v0.12.0".to_string());
         Ok(())
     }
+
+    #[cfg(not(target_os = "windows"))]
+    #[sealed_test]
+    fn should_create_gpg_signed_annotated_tag() -> Result<()> {
+        // Arrange
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR")?;
+        let repo = git_init_no_gpg()?;
+        run_cmd!(
+            gpg --import $crate_dir/tests/assets/pubkey.key;
+            gpg --import $crate_dir/tests/assets/privkey.key;
+            echo -e "5\ny\n" | gpg --no-tty --command-fd 0 --expert --edit-key test@cocogitto.org trust;
+            git config --local user.signingkey 24CAC643C7098768E2A90E1A2A8180559460836E;
+            git config --local tag.gpgSign true;
+        )?;
+        commit("chore: first commit")?;
+        let tag = Tag::from_str("1.0.0", None)?;
+
+        // Act
+        repo.create_annotated_tag(&tag, "Release 1.0.0", false)?;
+
+        // Assert
+        run_cmd!(git tag -v 1.0.0)?;
+        let message = run_fun!(git tag -l --format="%(contents:subject)" 1.0.0)?;
+        assert_that!(message).is_equal_to("Release 1.0.0".to_string());
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[sealed_test]
+    fn should_create_ssh_signed_tag() -> Result<()> {
+        // Arrange
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR")?;
+        let repo = git_init_no_gpg()?;
+        let public_key = fs::read_to_string(format!("{crate_dir}/tests/assets/sshkey.pub"))?;
+        fs::write(
+            "allowed_signers",
+            format!("test@cocogitto.org {public_key}"),
+        )?;
+        run_cmd!(
+            chmod 600 $crate_dir/tests/assets/sshkey;
+            git config --local user.email test@cocogitto.org;
+            git config --local user.signingkey $crate_dir/tests/assets/sshkey;
+            git config --local tag.gpgSign true;
+            git config --local gpg.format ssh;
+            git config --local gpg.ssh.allowedSignersFile allowed_signers;
+        )?;
+        commit("chore: first commit")?;
+        let tag = Tag::from_str("1.0.0", None)?;
+
+        // Act
+        repo.create_tag(&tag, true)?;
+
+        // Assert
+        run_cmd!(git tag -v 1.0.0)?;
+        Ok(())
+    }
 }
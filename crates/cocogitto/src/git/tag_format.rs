@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+/// Characters separating the package name from the rest of the tag, omitted with the
+/// package name in tags without package.
+const PACKAGE_SEPARATORS: &[char] = &['/', '@', '-', '_', '.'];
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Segment {
+    Literal(String),
+    Package,
+    Version,
+}
+
+/// A tag name template such as `{package}/v{version}`, see the `tag_format` setting.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TagFormat {
+    format: String,
+    segments: Vec<Segment>,
+}
+
+impl TryFrom<String> for TagFormat {
+    type Error = String;
+
+    fn try_from(format: String) -> Result<Self, Self::Error> {
+        TagFormat::parse(&format)
+    }
+}
+
+impl From<TagFormat> for String {
+    fn from(format: TagFormat) -> Self {
+        format.format
+    }
+}
+
+impl TagFormat {
+    /// Parse a template containing `{version}` once and `{package}` at most once.
+    pub(crate) fn parse(format: &str) -> Result<Self, String> {
+        let mut segments = vec![];
+        let mut remains = format;
+        while !remains.is_empty() {
+            let next = remains
+                .find("{version}")
+                .into_iter()
+                .chain(remains.find("{package}"))
+                .min();
+
+            match next {
+                Some(0) if remains.starts_with("{version}") => {
+                    segments.push(Segment::Version);
+                    remains = &remains["{version}".len()..];
+                }
+                Some(0) => {
+                    segments.push(Segment::Package);
+                    remains = &remains["{package}".len()..];
+                }
+                Some(idx) => {
+                    segments.push(Segment::Literal(remains[..idx].to_string()));
+                    remains = &remains[idx..];
+                }
+                None => {
+                    segments.push(Segment::Literal(remains.to_string()));
+                    remains = "";
+                }
+            }
+        }
+
+        let count = |expected: &Segment| segments.iter().filter(|s| *s == expected).count();
+        if count(&Segment::Version) != 1 || count(&Segment::Package) > 1 {
+            return Err(format!(
+                "invalid tag format '{format}', expected one {{version}} and at most one {{package}} placeholder"
+            ));
+        }
+
+        let adjacent_placeholders = segments
+            .windows(2)
+            .any(|pair| !matches!(pair, [Segment::Literal(_), _] | [_, Segment::Literal(_)]));
+        if adjacent_placeholders {
+            return Err(format!(
+                "invalid tag format '{format}', {{package}} and {{version}} must be separated"
+            ));
+        }
+
+        Ok(TagFormat {
+            format: format.to_string(),
+            segments,
+        })
+    }
+
+    /// The template as written in the settings.
+    pub fn as_str(&self) -> &str {
+        &self.format
+    }
+
+    pub(crate) fn has_package(&self) -> bool {
+        self.segments.contains(&Segment::Package)
+    }
+
+    /// The text before and after the version for the given package. Without package,
+    /// `{package}` is omitted along with the separator following it.
+    fn surroundings(&self, package: Option<&str>) -> (String, String) {
+        let mut before = String::new();
+        let mut after = String::new();
+        let mut seen_version = false;
+        let mut skip_separator = false;
+
+        for segment in &self.segments {
+            let text = match segment {
+                Segment::Version => {
+                    seen_version = true;
+                    continue;
+                }
+                Segment::Package => match package {
+                    Some(package) => package,
+                    None => {
+                        skip_separator = true;
+                        continue;
+                    }
+                },
+                Segment::Literal(literal) if skip_separator => {
+                    skip_separator = false;
+                    literal.trim_start_matches(PACKAGE_SEPARATORS)
+                }
+                Segment::Literal(literal) => literal,
+            };
+
+            if seen_version {
+                after.push_str(text);
+            } else {
+                before.push_str(text);
+            }
+        }
+
+        (before, after)
+    }
+
+    pub(crate) fn format(&self, package: Option<&str>, version: &str) -> String {
+        let (before, after) = self.surroundings(package);
+        format!("{before}{version}{after}")
+    }
+
+    /// The version part of a tag of the given package, `None` if the tag does not match.
+    pub(crate) fn version_part<'a>(&self, raw: &'a str, package: Option<&str>) -> Option<&'a str> {
+        let (before, after) = self.surroundings(package);
+        raw.strip_prefix(before.as_str())?
+            .strip_suffix(after.as_str())
+            .filter(|version| !version.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use speculoos::prelude::*;
+
+    use super::TagFormat;
+
+    #[test]
+    fn should_format_go_module_tags() {
+        let format = TagFormat::parse("{package}/v{version}").unwrap();
+
+        assert_that!(format.format(Some("pkg"), "1.2.3")).is_equal_to("pkg/v1.2.3".to_string());
+        assert_that!(format.format(None, "1.2.3")).is_equal_to("v1.2.3".to_string());
+    }
+
+    #[test]
+    fn should_extract_version_of_scoped_package_tag() {
+        let format = TagFormat::parse("{package}@{version}").unwrap();
+
+        let version = format.version_part("@scope/pkg@1.0.0", Some("@scope/pkg"));
+
+        assert_that!(version).is_equal_to(Some("1.0.0"));
+        assert_that!(format.version_part("1.0.0", None)).is_equal_to(Some("1.0.0"));
+    }
+
+    #[test]
+    fn should_not_match_other_package() {
+        let format = TagFormat::parse("{package}/v{version}").unwrap();
+
+        assert_that!(format.version_part("core-utils/v1.0.0", Some("core"))).is_none();
+        assert_that!(format.version_part("core/v1.0.0", Some("core-utils"))).is_none();
+        assert_that!(format.version_part("core/v1.0.0", None)).is_none();
+    }
+
+    #[test]
+    fn should_reject_invalid_formats() {
+        assert_that!(TagFormat::parse("v{package}")).is_err();
+        assert_that!(TagFormat::parse("{version}-{version}")).is_err();
+        assert_that!(TagFormat::parse("{package}{version}")).is_err();
+    }
+}
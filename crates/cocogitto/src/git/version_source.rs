@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use git2::Commit as Git2Commit;
+
+use crate::git::repository::Repository;
+use crate::git::tag::Tag;
+use crate::version_files::VersionFile;
+use crate::SETTINGS;
+
+impl Repository {
+    /// Read the current version from a `version_source` file at HEAD, `path` is the file path
+    /// relative to the repository root. The returned tag points to the commit that last changed
+    /// the version, following first parents.
+    pub(crate) fn get_source_version(
+        &self,
+        source: &VersionFile,
+        path: &Path,
+        package: Option<&str>,
+    ) -> Result<Tag> {
+        let head = self.get_head_commit()?;
+        let content = self
+            .file_content_at(&head, path)?
+            .ok_or_else(|| anyhow!("version source {} not found at HEAD", path.display()))?;
+        let raw = source
+            .current_version(&content)
+            .with_context(|| format!("failed to read version source {}", path.display()))?;
+
+        let mut commit = head;
+        while let Ok(parent) = commit.parent(0) {
+            // A parent without a readable version did not hold the current one either
+            let parent_version = self
+                .file_content_at(&parent, path)?
+                .and_then(|content| source.current_version(&content).ok());
+
+            if parent_version.as_deref() != Some(raw.as_str()) {
+                break;
+            }
+
+            commit = parent;
+        }
+
+        let version = SETTINGS
+            .version_scheme(package)
+            .parse(&raw)
+            .ok_or_else(|| anyhow!("invalid version '{raw}' in {}", path.display()))?;
+
+        let mut tag = Tag::create(version, package.map(str::to_string));
+        tag.oid = Some(commit.id());
+        Ok(tag)
+    }
+
+    fn file_content_at(&self, commit: &Git2Commit, path: &Path) -> Result<Option<String>> {
+        let entry = match commit.tree()?.get_path(path) {
+            Ok(entry) => entry,
+            Err(err) if err.code() == git2::ErrorCode::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let blob = entry.to_object(&self.0)?.peel_to_blob()?;
+        Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use anyhow::Result;
+    use cmd_lib::run_fun;
+    use git2::Oid;
+    use sealed_test::prelude::*;
+    use semver::Version;
+    use speculoos::prelude::*;
+
+    use crate::test_helpers::{commit, git_init_no_gpg};
+    use crate::version_files::VersionFile;
+
+    #[sealed_test]
+    fn should_read_version_from_commit_that_last_changed_it() -> Result<()> {
+        // Arrange
+        let repo = git_init_no_gpg()?;
+        std::fs::write("VERSION", "1.0.0\n")?;
+        run_fun!(git add VERSION;)?;
+        commit("chore: init")?;
+        commit("feat: feature")?;
+        std::fs::write("VERSION", "1.1.0\n")?;
+        run_fun!(git add VERSION;)?;
+        let version_commit = commit("chore(version): 1.1.0")?;
+        commit("fix: bug fix")?;
+
+        let source = VersionFile {
+            path: PathBuf::from("VERSION"),
+            ..Default::default()
+        };
+
+        // Act
+        let tag = repo.get_source_version(&source, &source.path, None)?;
+
+        // Assert
+        assert_that!(tag.version).is_equal_to(Version::new(1, 1, 0));
+        assert_that!(tag.oid).is_equal_to(Some(Oid::from_str(&version_commit)?));
+        Ok(())
+    }
+}
@@ -68,19 +68,23 @@ impl VersionSpan {
             prefix: SETTINGS.tag_prefix.clone(),
             version,
             oid: None,
+            legacy_name: None,
         });
 
         // According to the pest grammar, a `version` or `latest_version` token is expected first
-        let mut tag = match self.tokens.pop_front() {
+        let first_token = self.tokens.pop_front();
+        let bare_version = matches!(
+            first_token,
+            Some(Token::Version) | Some(Token::LatestVersion)
+        );
+        let mut tag = match first_token {
             Some(Token::Version) => version
                 .map(|version| version.prefixed_tag.clone())
                 .or(default_tag.clone())
-                .map(|tag| tag.strip_metadata())
                 .ok_or_else(|| anyhow!("No previous tag found to replace {{{{version}}}} version")),
             Some(Token::LatestVersion) => latest
                 .map(|version| version.prefixed_tag.clone())
                 .or(default_tag.clone())
-                .map(|tag| tag.strip_metadata())
                 .ok_or_else(|| anyhow!("No previous tag found to replace {{{{latest}}}} version")),
             Some(Token::LatestVersionTag) => latest
                 .map(|version| version.prefixed_tag.clone())
@@ -103,6 +107,11 @@ impl VersionSpan {
             _ => unreachable!("Unexpected parsing error"),
         }?;
 
+        // Once incremented, the version no longer matches the legacy tag
+        if !self.tokens.is_empty() {
+            tag.legacy_name = None;
+        }
+
         let mut amount = 1;
         let mut version_access_token: Option<VersionAccessToken> = None;
 
@@ -139,6 +148,8 @@ impl VersionSpan {
                 VersionAccessToken::Minor => tag.version.minor.to_string(),
                 VersionAccessToken::Patch => tag.version.patch.to_string(),
             })
+        } else if bare_version {
+            Ok(tag.formatted_version())
         } else {
             Ok(tag.to_string())
         }
@@ -271,6 +282,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(None, Some(&HookVersion::new(tag)))
@@ -309,6 +321,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(None, Some(&HookVersion::new(tag)))
@@ -326,6 +339,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(Some(&HookVersion::new(tag)), None)
@@ -364,6 +378,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(None, Some(&HookVersion::new(tag)))
@@ -403,6 +418,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(None, Some(&HookVersion::new(tag)))
@@ -548,6 +564,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(None, Some(&HookVersion::new(tag)))
@@ -606,6 +623,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         let tag = Tag {
@@ -613,6 +631,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 1, 0),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(
@@ -657,6 +676,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 0, 0),
             oid: None,
+            legacy_name: None,
         };
 
         let tag = Tag {
@@ -664,6 +684,7 @@ mod test {
             prefix: Some("v".to_string()),
             version: Version::new(1, 2, 3),
             oid: None,
+            legacy_name: None,
         };
 
         hook.insert_versions(
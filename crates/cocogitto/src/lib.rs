@@ -32,6 +32,8 @@ pub mod hook;
 pub mod log;
 /// Settings module containing configuration structures and functions for Cocogitto
 pub mod settings;
+pub mod version_files;
+pub mod version_scheme;
 
 pub const DEFAULT_CONFIG_PATH: &str = "cog.toml";
 static CONFIG_PATH: OnceLock<String> = OnceLock::new();
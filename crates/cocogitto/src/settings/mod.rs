@@ -4,17 +4,27 @@ use std::fmt;
 use std::fmt::Formatter;
 use std::path::PathBuf;
 
+use crate::conventional::branch::BranchPattern;
+use crate::conventional::bump_rule::BumpRule;
 use crate::conventional::changelog::context::RemoteContext;
 use crate::conventional::commit::CommitConfig;
+use crate::conventional::issues::IssuesSettings;
+use crate::conventional::lint::LintSettings;
+use crate::conventional::version::MaintenanceRange;
+use crate::git::legacy_tag::LegacyTagPattern;
 use crate::git::repository::Repository;
+use crate::git::tag_format::TagFormat;
 use crate::{get_config_path, SETTINGS};
 
 use crate::conventional::changelog::error::ChangelogError;
 use crate::conventional::changelog::template::Template;
 use crate::hook::Hooks;
 use crate::settings::error::SettingError;
-use config::{Config, File, FileFormat};
+use crate::version_files::VersionFile;
+use crate::version_scheme::{CalVerFormat, VersionScheme, VersionSchemeKind};
+use config::{Config, ConfigError, File, FileFormat};
 use conventional_commit_parser::commit::CommitType;
+use globset::{Glob, GlobMatcher};
 use maplit::hashmap;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -22,6 +32,9 @@ use std::path::Path;
 pub(crate) type AuthorSettings = Vec<AuthorSetting>;
 
 mod error;
+mod scope;
+
+pub use scope::ScopePattern;
 
 /// # HookType
 /// Represents the type of hook that can be executed during version bumping.
@@ -83,6 +96,10 @@ pub struct MonorepoConfig {
     pub resolver: Option<String>,
     /// Monorepo packages configuration.
     pub packages: HashMap<String, MonoRepoPackage>,
+    /// Allow package names as commit scopes, in addition to the global `scopes` list.
+    pub package_scopes: bool,
+    /// Overrides `bump_commit_message` for monorepo bumps.
+    pub bump_commit_message: Option<String>,
 }
 
 #[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
@@ -124,6 +141,9 @@ pub struct Settings {
     pub disable_changelog: bool,
     /// Whether to create a bump commit or not.
     pub disable_bump_commit: bool,
+    /// Tera template for the bump commit message, defaults to `chore(version): {{version_tag}}`.
+    /// Also used for package and monorepo bumps unless they define their own.
+    pub bump_commit_message: Option<String>,
     /// Activate or deactivate global tag generation for mono-repository.
     pub generate_mono_repository_global_tag: bool,
     /// Activate or deactivate package tag generation for mono-repository.
@@ -132,10 +152,27 @@ pub struct Settings {
     pub monorepo_version_separator: Option<String>,
     /// A list of glob patterns to allow bumping only on matching branches.
     pub branch_whitelist: Vec<String>,
+    /// Release channels, bumping is only allowed on the branches matching one of them.
+    pub release_branches: Vec<ReleaseBranch>,
+    /// Branch naming conventions checked by `cog check-branch`, e.g. `<type>/<ticket>-<slug>`.
+    /// Supported placeholders are `<type>` (a known commit type), `<scope>`, `<ticket>` and `<slug>`,
+    /// `*` matches anything.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Vec<String>"))]
+    pub branch_patterns: Vec<BranchPattern>,
     /// Set a tag prefix value for cocogitto. For instance if you have a `v`
     /// as a tag prefix, cocogitto will generate versions starting with `v` and
     /// commands like `cog changelog` will pick only those versions.
     pub tag_prefix: Option<String>,
+    /// Template of the tag names, replacing `tag_prefix` and `monorepo_version_separator`.
+    /// `{version}` is replaced with the version and `{package}` with the package name,
+    /// e.g. `{package}/v{version}`. Tags without package omit `{package}` and the separator following it.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Option<String>"))]
+    pub tag_format: Option<TagFormat>,
+    /// Regexes mapping the names of legacy release tags, such as `release-1.2`, to versions with
+    /// the `major`, `minor`, `patch` and `pre` named capture groups. Only `major` is required.
+    /// Matching tags are used to find previous releases but never created.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Vec<String>"))]
+    pub legacy_tag_patterns: Vec<LegacyTagPattern>,
     /// Default pre-release pattern to be used when auto-incrementing pre-release versions.
     /// It must contain exactly one wildcard `*` to be replaced by the numeric identifier.
     pub pre: String,
@@ -164,8 +201,31 @@ pub struct Settings {
     pub bump_profiles: HashMap<String, BumpProfile>,
     /// Monorepo configuration.
     pub monorepo: Option<MonorepoConfig>,
-    /// List of valid commit scopes.
-    pub scopes: Option<Vec<String>>,
+    /// List of valid commit scopes. Entries are glob patterns (e.g. `api-*`)
+    /// or regular expressions wrapped in slashes (e.g. `/^v\d+$/`).
+    /// Breaking: entries containing glob characters used to be compared literally.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Option<Vec<String>>"))]
+    pub scopes: Option<Vec<ScopePattern>>,
+    /// Commit message lint rules.
+    pub lint: LintSettings,
+    /// Issue references extraction and validation.
+    pub issues: IssuesSettings,
+    /// Files updated with the new version before the bump commit.
+    pub version_files: Vec<VersionFile>,
+    /// File the current version is read from instead of the latest tag, e.g. `Cargo.toml`.
+    /// Auto bumps read the commits following the one that last changed the version.
+    pub version_source: Option<VersionFile>,
+    /// Rules mapping commits to a version increment, overriding the commit types configuration.
+    pub bump_rules: Vec<BumpRule>,
+    /// While the major version is 0, breaking changes bump the minor version and features
+    /// the patch version. Use `cog bump --graduate` to release 1.0.0.
+    pub initial_development: bool,
+    /// Versioning scheme, `semver` or `calver`.
+    pub version_scheme: VersionSchemeKind,
+    /// Calendar versioning format, one or two date tokens (`YYYY`, `YY`, `0Y`, `MM`, `0M`,
+    /// `WW`, `0W`, `DD`, `0D`) followed by `MICRO`.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "String"))]
+    pub calver_format: CalVerFormat,
 }
 
 impl Default for Settings {
@@ -176,11 +236,16 @@ impl Default for Settings {
             ignore_fixup_commits: false,
             disable_changelog: false,
             disable_bump_commit: false,
+            bump_commit_message: None,
             generate_mono_repository_global_tag: true,
             generate_mono_repository_package_tags: true,
             monorepo_version_separator: None,
             branch_whitelist: vec![],
+            release_branches: vec![],
+            branch_patterns: vec![],
             tag_prefix: None,
+            tag_format: None,
+            legacy_tag_patterns: vec![],
             pre: "alpha.*".to_string(),
             skip_ci: "[skip ci]".to_string(),
             skip_untracked: false,
@@ -194,6 +259,14 @@ impl Default for Settings {
             bump_profiles: Default::default(),
             monorepo: Default::default(),
             scopes: Default::default(),
+            lint: Default::default(),
+            issues: Default::default(),
+            version_files: vec![],
+            version_source: None,
+            bump_rules: vec![],
+            initial_development: false,
+            version_scheme: VersionSchemeKind::Semver,
+            calver_format: CalVerFormat::default(),
         }
     }
 }
@@ -384,6 +457,20 @@ pub struct MonoRepoPackage {
     pub post_bump_hooks: Option<Vec<String>>,
     /// Custom profile to override `pre_bump_hooks`, `post_bump_hooks`.
     pub bump_profiles: HashMap<String, BumpProfile>,
+    /// Files updated with the package version before the bump commit, relative to the package path.
+    pub version_files: Vec<VersionFile>,
+    /// File the package version is read from instead of the latest package tag,
+    /// relative to the package path.
+    pub version_source: Option<VersionFile>,
+    /// Overrides `initial_development` for this package.
+    pub initial_development: Option<bool>,
+    /// Overrides `version_scheme` for this package.
+    pub version_scheme: Option<VersionSchemeKind>,
+    /// Overrides `calver_format` for this package.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Option<String>"))]
+    pub calver_format: Option<CalVerFormat>,
+    /// Overrides `bump_commit_message` for this package bumps.
+    pub bump_commit_message: Option<String>,
 }
 
 impl Default for &MonoRepoPackage {
@@ -398,6 +485,12 @@ impl Default for &MonoRepoPackage {
             bump_profiles: Default::default(),
             public_api: true,
             bump_order: None,
+            version_files: vec![],
+            version_source: None,
+            initial_development: None,
+            version_scheme: None,
+            calver_format: None,
+            bump_commit_message: None,
         });
 
         Box::leak(package)
@@ -416,6 +509,12 @@ impl Default for MonoRepoPackage {
             bump_profiles: Default::default(),
             public_api: true,
             bump_order: None,
+            version_files: vec![],
+            version_source: None,
+            initial_development: None,
+            version_scheme: None,
+            calver_format: None,
+            bump_commit_message: None,
         }
     }
 }
@@ -452,7 +551,7 @@ impl MonoRepoPackage {
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 #[serde(deny_unknown_fields, default)]
 pub struct Changelog {
-    /// Template to use for changelog generation. Can be "remote", "full_hash" or a custom template path
+    /// Template to use for changelog generation. Can be "default", "remote", "full_hash", "keepachangelog", "asciidoc" or a custom template path
     pub template: Option<String>,
     /// Template to use for package changelogs in monorepos
     pub package_template: Option<String>,
@@ -466,6 +565,59 @@ pub struct Changelog {
     pub repository: Option<String>,
     /// Author mappings for changelog generation
     pub authors: AuthorSettings,
+    /// Line after which new releases are inserted in an existing changelog
+    pub insertion_marker: String,
+    /// Where to insert new releases when the insertion marker is missing, the marker is then added
+    /// to the changelog. Can be "auto", "separator", "heading" or "none"
+    pub insertion_fallback: InsertionFallback,
+    /// What happens to the pre-release sections of the changelog when `cog bump --promote`
+    /// releases their stable version. Can be "keep" or "remove"
+    pub promoted_pre_releases: PromotedPreReleases,
+}
+
+/// # InsertionFallback
+/// Strategy used to find where new releases go in a changelog that has no insertion marker.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InsertionFallback {
+    /// Use "separator" for the built-in cocogitto templates and "heading" for
+    /// the keepachangelog and asciidoc templates
+    #[default]
+    Auto,
+    /// Insert after the first `- - -` line
+    Separator,
+    /// Insert before the first release heading (`## ` in markdown, `== ` in asciidoc),
+    /// or at the end of the file
+    Heading,
+    /// Fail if the insertion marker is missing
+    None,
+}
+
+/// # PromotedPreReleases
+/// What happens to the changelog sections of promoted pre-releases.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PromotedPreReleases {
+    /// Keep the pre-release sections below the stable release
+    #[default]
+    Keep,
+    /// Remove the pre-release sections, the stable release covers all their commits
+    Remove,
+}
+
+impl fmt::Display for InsertionFallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let fallback = match self {
+            InsertionFallback::Auto => "auto",
+            InsertionFallback::Separator => "separator",
+            InsertionFallback::Heading => "heading",
+            InsertionFallback::None => "none",
+        };
+
+        write!(f, "{fallback}")
+    }
 }
 
 impl Default for Changelog {
@@ -478,6 +630,9 @@ impl Default for Changelog {
             owner: None,
             repository: None,
             authors: vec![],
+            insertion_marker: "<!-- cog:releases -->".to_string(),
+            insertion_fallback: InsertionFallback::Auto,
+            promoted_pre_releases: PromotedPreReleases::Keep,
         }
     }
 }
@@ -535,6 +690,13 @@ pub fn changelog_path() -> &'static PathBuf {
     &SETTINGS.changelog.path
 }
 
+fn pattern_strings(patterns: &[ScopePattern]) -> Vec<String> {
+    patterns
+        .iter()
+        .map(|pattern| pattern.as_str().to_string())
+        .collect()
+}
+
 /// # BumpProfile
 /// A custom profile for configuring hooks that run before and after version bumps.
 ///
@@ -559,6 +721,89 @@ pub struct BumpProfile {
     pub post_bump_hooks: Vec<String>,
 }
 
+/// # ReleaseBranch
+/// A release channel, the versions released from the branches matching `name`.
+///
+///  **Example :**
+/// ```toml
+/// [[release_branches]]
+/// name = "main"
+///
+/// [[release_branches]]
+/// name = "next"
+/// pre = "beta.*"
+///
+/// [[release_branches]]
+/// name = "alpha"
+/// pre = "alpha.*"
+/// disable_changelog = true
+/// ```
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ReleaseBranch {
+    /// Glob pattern matching the branches of this channel.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "String"))]
+    pub name: ReleaseBranchName,
+    /// Pre-release of the versions released from this channel, either a pattern such as `beta.*`
+    /// or an exact pre-release. Stable versions are released if unset.
+    pub pre: Option<String>,
+    /// Don't generate the changelog when bumping from this channel.
+    #[serde(default)]
+    pub disable_changelog: bool,
+    /// Maintenance line of this channel, `MAJOR.x` or `MAJOR.MINOR.x`. Tags outside of the range
+    /// are ignored and bumps leaving the range are refused.
+    #[cfg_attr(feature = "docgen", cog_schemars(with = "Option<String>"))]
+    pub range: Option<MaintenanceRange>,
+}
+
+impl ReleaseBranch {
+    /// Whether the branch belongs to this channel.
+    pub fn matches(&self, branch: &str) -> bool {
+        self.name.matcher.is_match(branch)
+    }
+}
+
+/// The glob pattern of a release channel branches, compiled when loading the settings.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ReleaseBranchName {
+    pattern: String,
+    matcher: GlobMatcher,
+}
+
+impl ReleaseBranchName {
+    /// The pattern as written in the settings.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl PartialEq for ReleaseBranchName {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for ReleaseBranchName {}
+
+impl TryFrom<String> for ReleaseBranchName {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        let matcher = Glob::new(&pattern)
+            .map_err(|err| format!("invalid release branch pattern: {err}"))?
+            .compile_matcher();
+        Ok(ReleaseBranchName { pattern, matcher })
+    }
+}
+
+impl From<ReleaseBranchName> for String {
+    fn from(name: ReleaseBranchName) -> Self {
+        name.pattern
+    }
+}
+
 impl Settings {
     // Fails only if config exists and is malformed
     pub(crate) fn get<T: TryInto<Settings, Error = SettingError>>(
@@ -603,13 +848,78 @@ impl Settings {
             .collect()
     }
 
-    /// Returns the configured commit scopes, if any.
+    /// Returns the configured commit scopes, if any, as written in the settings.
+    ///
+    /// Entries are patterns, use [`Settings::is_scope_allowed`] to check a scope against them.
     ///
     /// # Returns
     ///
-    /// * `Option<Vec<String>>` - List of valid commit scopes, or None if not configured
+    /// * `Option<Vec<String>>` - List of valid commit scope patterns, or None if not configured
     pub fn commit_scopes(&self) -> Option<Vec<String>> {
-        self.scopes.clone()
+        self.scopes.as_ref().map(|scopes| pattern_strings(scopes))
+    }
+
+    /// Returns the scope patterns allowed for the given commit type, if any.
+    ///
+    /// Scopes defined in the commit type configuration take precedence over the global
+    /// `scopes` list. When `monorepo.package_scopes` is enabled, package names are
+    /// added to the global list.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Vec<String>>` - Allowed scope patterns, or None if any scope is allowed
+    pub fn allowed_scopes(&self, commit_type: &CommitType) -> Option<Vec<String>> {
+        if let Some(type_scopes) = self.type_scopes(commit_type) {
+            return Some(pattern_strings(type_scopes));
+        }
+
+        let mut scopes = self.commit_scopes();
+        if let Some(packages) = self.package_scopes() {
+            scopes
+                .get_or_insert_with(Vec::new)
+                .extend(packages.cloned());
+        }
+
+        scopes
+    }
+
+    /// Checks whether the given scope is allowed for the given commit type.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - true if no scope restriction applies or if the scope matches an allowed pattern
+    pub fn is_scope_allowed(&self, commit_type: &CommitType, scope: &str) -> bool {
+        if let Some(type_scopes) = self.type_scopes(commit_type) {
+            return type_scopes.iter().any(|pattern| pattern.matches(scope));
+        }
+
+        match (&self.scopes, self.package_scopes()) {
+            (None, None) => true,
+            (patterns, packages) => {
+                patterns
+                    .iter()
+                    .flatten()
+                    .any(|pattern| pattern.matches(scope))
+                    || packages
+                        .into_iter()
+                        .flatten()
+                        .any(|package| package == scope)
+            }
+        }
+    }
+
+    fn type_scopes(&self, commit_type: &CommitType) -> Option<&[ScopePattern]> {
+        self.commit_types
+            .get(commit_type.as_ref())
+            .and_then(|config| config.scopes.as_deref())
+    }
+
+    /// Package names allowed as scopes when `monorepo.package_scopes` is enabled.
+    fn package_scopes(&self) -> Option<impl Iterator<Item = &String>> {
+        self.monorepo
+            .as_ref()
+            .filter(|monorepo| monorepo.package_scopes)
+            .map(|monorepo| monorepo.packages.keys())
     }
 
     fn default_commit_config() -> HashMap<CommitType, CommitConfig> {
@@ -670,6 +980,7 @@ impl Settings {
         let template = match template {
             "remote" => "package_remote",
             "full_hash" => "package_full_hash",
+            // Package changelogs share the `keepachangelog` and `asciidoc` templates
             template => template,
         };
 
@@ -693,6 +1004,8 @@ impl Settings {
         let template = match template {
             "remote" => "monorepo_remote",
             "full_hash" => "monorepo_full_hash",
+            "keepachangelog" => "monorepo_keepachangelog",
+            "asciidoc" => "monorepo_asciidoc",
             template => template,
         };
 
@@ -700,6 +1013,100 @@ impl Settings {
         Template::from_arg(template, context, false)
     }
 
+    /// Whether the initial development policy applies to the given package,
+    /// or to the global version if `package` is `None`.
+    pub fn initial_development(&self, package: Option<&str>) -> bool {
+        package
+            .and_then(|package| self.monorepo.as_ref()?.packages.get(package))
+            .and_then(|package| package.initial_development)
+            .unwrap_or(self.initial_development)
+    }
+
+    /// Every bump commit message template: global, monorepo and packages.
+    fn bump_commit_templates(&self) -> impl Iterator<Item = &str> {
+        let monorepo = self.monorepo.iter().flat_map(|monorepo| {
+            std::iter::once(&monorepo.bump_commit_message).chain(
+                monorepo
+                    .packages
+                    .values()
+                    .map(|package| &package.bump_commit_message),
+            )
+        });
+
+        std::iter::once(&self.bump_commit_message)
+            .chain(monorepo)
+            .filter_map(|template| template.as_deref())
+    }
+
+    /// The release channel of the branch, the first matching `release_branches` entry.
+    pub fn release_branch(&self, branch: &str) -> Option<&ReleaseBranch> {
+        self.release_branches
+            .iter()
+            .find(|release_branch| release_branch.matches(branch))
+    }
+
+    /// The `version_source` of the given package, or of the global version if `package` is `None`,
+    /// along with its path relative to the repository root.
+    pub(crate) fn version_source(&self, package: Option<&str>) -> Option<(&VersionFile, PathBuf)> {
+        match package {
+            Some(package) => {
+                let package = self.monorepo.as_ref()?.packages.get(package)?;
+                let source = package.version_source.as_ref()?;
+                Some((source, package.path.join(&source.path)))
+            }
+            None => self
+                .version_source
+                .as_ref()
+                .map(|source| (source, source.path.clone())),
+        }
+    }
+
+    /// The version scheme of the given package, or of the global version if `package` is `None`.
+    pub fn version_scheme(&self, package: Option<&str>) -> VersionScheme<'_> {
+        let package = package.and_then(|package| self.monorepo.as_ref()?.packages.get(package));
+
+        let kind = package
+            .and_then(|package| package.version_scheme)
+            .unwrap_or(self.version_scheme);
+
+        match kind {
+            VersionSchemeKind::Semver => VersionScheme::SemVer,
+            VersionSchemeKind::Calver => {
+                let format = package
+                    .and_then(|package| package.calver_format.as_ref())
+                    .unwrap_or(&self.calver_format);
+                VersionScheme::CalVer(format)
+            }
+        }
+    }
+
+    /// The `tag_format` template, if configured.
+    fn validate_tag_format(&self) -> Result<(), String> {
+        let Some(tag_format) = &self.tag_format else {
+            return Ok(());
+        };
+
+        if self.tag_prefix.is_some() || self.monorepo_version_separator.is_some() {
+            return Err(
+                "tag_format cannot be combined with tag_prefix or monorepo_version_separator"
+                    .to_string(),
+            );
+        }
+
+        let has_packages = self
+            .monorepo
+            .as_ref()
+            .is_some_and(|monorepo| !monorepo.packages.is_empty());
+        if has_packages && !tag_format.has_package() {
+            return Err(format!(
+                "invalid tag format '{}', a {{package}} placeholder is required in a monorepo",
+                tag_format.as_str()
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Returns the version separator for monorepo package tags.
     ///
     /// # Returns
@@ -793,8 +1200,23 @@ impl TryFrom<&Repository> for Settings {
                         .add_source(File::from(settings_path))
                         .build()
                         .map_err(SettingError::from)?
-                        .try_deserialize()
+                        .try_deserialize::<Settings>()
                         .map_err(SettingError::from)
+                        .and_then(|settings| {
+                            settings
+                                .validate_tag_format()
+                                .map_err(|err| SettingError::from(ConfigError::Message(err)))?;
+                            for template in settings.bump_commit_templates() {
+                                tera::Tera::default()
+                                    .add_raw_template("bump_commit_message", template)
+                                    .map_err(|err| {
+                                        SettingError::from(ConfigError::Message(format!(
+                                            "invalid bump_commit_message template: {err}"
+                                        )))
+                                    })?;
+                            }
+                            Ok(settings)
+                        })
                 } else {
                     Ok(Settings::default())
                 }
@@ -812,8 +1234,122 @@ mod test {
     use sealed_test::prelude::*;
     use speculoos::prelude::*;
 
+    use crate::settings::Settings;
     use crate::{test_helpers::git_init_no_gpg, COMMITS_METADATA};
 
+    #[test]
+    fn should_match_scope_patterns() -> anyhow::Result<()> {
+        // Arrange
+        let settings = Settings::try_from(
+            r#"
+scopes = ["core", "api-*", "/^v[0-9]+$/"]
+
+[commit_types]
+ci = { scopes = ["github", "gitlab"] }
+"#
+            .to_string(),
+        )?;
+
+        // Assert
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "core")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "api-login")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "v2")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "vx")).is_false();
+        assert_that!(settings.is_scope_allowed(&CommitType::Ci, "github")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Ci, "core")).is_false();
+        Ok(())
+    }
+
+    #[test]
+    fn should_match_escaped_glob_scope_literally() -> anyhow::Result<()> {
+        // Arrange
+        let settings = Settings::try_from(r#"scopes = ["db?", "cache[?]"]"#.to_string())?;
+
+        // Assert
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "db?")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "dbs")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "cache?")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "caches")).is_false();
+        assert_that!(settings.commit_scopes())
+            .is_equal_to(Some(vec!["db?".to_string(), "cache[?]".to_string()]));
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_invalid_scope_patterns() {
+        let settings = Settings::try_from(r#"scopes = ["core", "/^v[0-9+$/"]"#.to_string());
+
+        assert_that!(settings).is_err().matches(|err| {
+            err.to_string()
+                .contains("invalid scope pattern '/^v[0-9+$/'")
+        });
+    }
+
+    #[test]
+    fn should_reject_invalid_maintenance_ranges() {
+        let settings = Settings::try_from(
+            r#"
+[[release_branches]]
+name = "release/1.x"
+range = "1.x.x"
+"#
+            .to_string(),
+        );
+
+        assert_that!(settings).is_err().matches(|err| {
+            err.to_string()
+                .contains("invalid maintenance range '1.x.x'")
+        });
+    }
+
+    #[test]
+    fn should_reject_invalid_release_branch_patterns() {
+        let settings = Settings::try_from(
+            r#"
+[[release_branches]]
+name = "release/["
+"#
+            .to_string(),
+        );
+
+        assert_that!(settings)
+            .is_err()
+            .matches(|err| err.to_string().contains("invalid release branch pattern"));
+    }
+
+    #[test]
+    fn should_reject_invalid_tag_format() {
+        let settings = Settings::try_from(r#"tag_format = "v{version}{version}""#.to_string());
+
+        assert_that!(settings).is_err().matches(|err| {
+            err.to_string()
+                .contains("invalid tag format 'v{version}{version}'")
+        });
+    }
+
+    #[test]
+    fn should_allow_package_names_as_scopes() -> anyhow::Result<()> {
+        // Arrange
+        let settings = Settings::try_from(
+            r#"
+scopes = ["docs"]
+
+[monorepo]
+package_scopes = true
+
+[monorepo.packages.one]
+path = "one"
+"#
+            .to_string(),
+        )?;
+
+        // Assert
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "one")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "docs")).is_true();
+        assert_that!(settings.is_scope_allowed(&CommitType::Feature, "two")).is_false();
+        Ok(())
+    }
+
     #[sealed_test]
     fn should_disable_default_commit_type() -> anyhow::Result<()> {
         git_init_no_gpg()?;
@@ -0,0 +1,88 @@
+use std::fmt;
+
+use globset::{Glob, GlobMatcher};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A commit scope pattern from the `scopes` settings, compiled when loading the settings.
+/// Patterns wrapped in slashes are regular expressions, other patterns are globs.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ScopePattern {
+    pattern: String,
+    matcher: ScopeMatcher,
+}
+
+#[derive(Debug, Clone)]
+enum ScopeMatcher {
+    Regex(Regex),
+    Glob(GlobMatcher),
+}
+
+impl ScopePattern {
+    /// Compile a scope pattern.
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let regex = pattern
+            .strip_prefix('/')
+            .and_then(|pattern| pattern.strip_suffix('/'));
+
+        let matcher = match regex {
+            Some(regex) => Regex::new(regex)
+                .map(ScopeMatcher::Regex)
+                .map_err(|err| format!("invalid scope pattern '{pattern}': {err}"))?,
+            None => Glob::new(pattern)
+                .map(|glob| ScopeMatcher::Glob(glob.compile_matcher()))
+                .map_err(|err| format!("invalid scope pattern '{pattern}': {err}"))?,
+        };
+
+        Ok(ScopePattern {
+            pattern: pattern.to_string(),
+            matcher,
+        })
+    }
+
+    /// The pattern as written in the settings.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Whether the scope matches this pattern.
+    pub fn matches(&self, scope: &str) -> bool {
+        if self.pattern == scope {
+            return true;
+        }
+
+        match &self.matcher {
+            ScopeMatcher::Regex(regex) => regex.is_match(scope),
+            ScopeMatcher::Glob(glob) => glob.is_match(scope),
+        }
+    }
+}
+
+impl PartialEq for ScopePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for ScopePattern {}
+
+impl fmt::Display for ScopePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl TryFrom<String> for ScopePattern {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        ScopePattern::parse(&pattern)
+    }
+}
+
+impl From<ScopePattern> for String {
+    fn from(pattern: ScopePattern) -> Self {
+        pattern.pattern
+    }
+}
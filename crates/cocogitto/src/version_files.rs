@@ -0,0 +1,660 @@
+use std::fmt::Write;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use itertools::Itertools;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use toml_edit::{DocumentMut, Formatted, Item, Value};
+
+/// # VersionFile
+/// A file holding the project version, updated by `cog bump` before the bump commit.
+///
+/// The updater is inferred from the file name for `Cargo.toml`, `package.json`, `pom.xml`,
+/// `pyproject.toml` and `VERSION`, other files need a `pattern` or a `line`.
+///
+///  **Example :**
+/// ```toml
+/// [[version_files]]
+/// path = "Cargo.toml"
+///
+/// [[version_files]]
+/// path = "README.md"
+/// line = "cocogitto = \"{version}\""
+///
+/// [[version_files]]
+/// path = "src/version.rs"
+/// pattern = "VERSION: &str = \"(?<version>[^\"]+)\""
+/// ```
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields, default)]
+pub struct VersionFile {
+    /// Path to the file, relative to the repository root dir,
+    /// or to the package path for package version files.
+    pub path: PathBuf,
+    /// Updater to use, inferred from `pattern`, `line` or the file name if not set.
+    pub format: Option<VersionFileFormat>,
+    /// Regular expression used by the `regex` updater. Every match of the `version` named group,
+    /// the first capture group, or the whole match is replaced with the new version.
+    pub pattern: Option<String>,
+    /// Line pattern used by the `line` updater, `{version}` matches the current version
+    /// and is replaced with the new one, e.g. `VERSION = "{version}"`.
+    pub line: Option<String>,
+}
+
+/// # VersionFileFormat
+/// Built-in version file updaters.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionFileFormat {
+    /// `package.version` and `workspace.package.version` of a `Cargo.toml`
+    Cargo,
+    /// The top level `version` of a `package.json`
+    PackageJson,
+    /// The project `<version>` of a `pom.xml`
+    Pom,
+    /// `project.version` or `tool.poetry.version` of a `pyproject.toml`
+    Pyproject,
+    /// Replace the matches of `pattern`
+    Regex,
+    /// Replace the lines matching `line`
+    Line,
+    /// The whole content of a plain text file such as `VERSION`
+    Plain,
+}
+
+/// The content of a version file before and after a version update.
+#[derive(Debug)]
+pub struct VersionFileUpdate {
+    /// Path to the file, relative to the repository root dir.
+    pub path: PathBuf,
+    /// The file on disk, `path` resolved against the repository workdir.
+    pub file: PathBuf,
+    pub previous: String,
+    pub updated: String,
+}
+
+impl VersionFile {
+    pub fn format(&self) -> Result<VersionFileFormat> {
+        if let Some(format) = self.format {
+            return Ok(format);
+        }
+
+        if self.pattern.is_some() {
+            return Ok(VersionFileFormat::Regex);
+        }
+
+        if self.line.is_some() {
+            return Ok(VersionFileFormat::Line);
+        }
+
+        match self.path.file_name().and_then(|name| name.to_str()) {
+            Some("Cargo.toml") => Ok(VersionFileFormat::Cargo),
+            Some("package.json") => Ok(VersionFileFormat::PackageJson),
+            Some("pom.xml") => Ok(VersionFileFormat::Pom),
+            Some("pyproject.toml") => Ok(VersionFileFormat::Pyproject),
+            Some("VERSION") => Ok(VersionFileFormat::Plain),
+            _ => bail!(
+                "cannot infer the format of version file {}, set `format`, `pattern` or `line`",
+                self.path.display()
+            ),
+        }
+    }
+
+    /// Compute the new content of the version file, `root` is the directory `path` is relative to,
+    /// relative itself to the repository `workdir`.
+    pub fn update(&self, workdir: &Path, root: &Path, version: &str) -> Result<VersionFileUpdate> {
+        let path = root.join(&self.path);
+        let file = workdir.join(&path);
+        let previous = fs::read_to_string(&file)
+            .with_context(|| format!("failed to read version file {}", path.display()))?;
+
+        let updated = match self.format()? {
+            VersionFileFormat::Cargo => update_cargo(&previous, version),
+            VersionFileFormat::PackageJson => update_package_json(&previous, version),
+            VersionFileFormat::Pom => update_pom(&previous, version),
+            VersionFileFormat::Pyproject => update_pyproject(&previous, version),
+            VersionFileFormat::Regex => {
+                let pattern = self
+                    .pattern
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("the `regex` format requires a `pattern`"))?;
+                update_regex(&previous, &Regex::new(pattern)?, version)
+            }
+            VersionFileFormat::Line => {
+                let line = self
+                    .line
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("the `line` format requires a `line`"))?;
+                update_regex(&previous, &line_regex(line)?, version)
+            }
+            VersionFileFormat::Plain => {
+                plain_version(&previous).map(|range| replace_range(&previous, range, version))
+            }
+        }
+        .with_context(|| format!("failed to update version file {}", path.display()))?;
+
+        Ok(VersionFileUpdate {
+            path,
+            file,
+            previous,
+            updated,
+        })
+    }
+
+    /// Read the version held by the content of the version file.
+    pub fn current_version(&self, content: &str) -> Result<String> {
+        let version = match self.format()? {
+            VersionFileFormat::Cargo => toml_version(
+                content,
+                &[
+                    &["package", "version"],
+                    &["workspace", "package", "version"],
+                ],
+            )?,
+            VersionFileFormat::Pyproject => toml_version(
+                content,
+                &[&["project", "version"], &["tool", "poetry", "version"]],
+            )?,
+            VersionFileFormat::PackageJson => content[package_json_version(content)?].to_string(),
+            VersionFileFormat::Pom => content[pom_version(content)?].to_string(),
+            VersionFileFormat::Regex => {
+                let pattern = self
+                    .pattern
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("the `regex` format requires a `pattern`"))?;
+                regex_version(content, &Regex::new(pattern)?)?
+            }
+            VersionFileFormat::Line => {
+                let line = self
+                    .line
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("the `line` format requires a `line`"))?;
+                regex_version(content, &line_regex(line)?)?
+            }
+            VersionFileFormat::Plain => content[plain_version(content)?].to_string(),
+        };
+
+        Ok(version)
+    }
+}
+
+impl VersionFileUpdate {
+    pub fn write(&self) -> Result<()> {
+        fs::write(&self.file, &self.updated)
+            .with_context(|| format!("failed to write version file {}", self.path.display()))
+    }
+
+    /// A unified diff of the changed lines.
+    pub fn diff(&self) -> String {
+        let path = self.path.display();
+        let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+        let previous: Vec<&str> = self.previous.lines().collect();
+        let updated: Vec<&str> = self.updated.lines().collect();
+
+        if previous.len() != updated.len() {
+            let _ = writeln!(diff, "@@ -1,{} +1,{} @@", previous.len(), updated.len());
+            for line in &previous {
+                let _ = writeln!(diff, "-{line}");
+            }
+            for line in &updated {
+                let _ = writeln!(diff, "+{line}");
+            }
+            return diff;
+        }
+
+        for (number, (before, after)) in previous.iter().zip(&updated).enumerate() {
+            if before != after {
+                let number = number + 1;
+                let _ = writeln!(diff, "@@ -{number} +{number} @@\n-{before}\n+{after}");
+            }
+        }
+
+        diff
+    }
+}
+
+fn update_cargo(content: &str, version: &str) -> Result<String> {
+    let mut document: DocumentMut = content.parse()?;
+    let package = document
+        .get_mut("package")
+        .and_then(|package| package.get_mut("version"))
+        .is_some_and(|item| set_toml_version(item, version));
+    let workspace = document
+        .get_mut("workspace")
+        .and_then(|workspace| workspace.get_mut("package"))
+        .and_then(|package| package.get_mut("version"))
+        .is_some_and(|item| set_toml_version(item, version));
+
+    ensure!(
+        package || workspace,
+        "no `package.version` or `workspace.package.version` found"
+    );
+    Ok(document.to_string())
+}
+
+fn update_pyproject(content: &str, version: &str) -> Result<String> {
+    let mut document: DocumentMut = content.parse()?;
+    let project = document
+        .get_mut("project")
+        .and_then(|project| project.get_mut("version"))
+        .is_some_and(|item| set_toml_version(item, version));
+    let poetry = document
+        .get_mut("tool")
+        .and_then(|tool| tool.get_mut("poetry"))
+        .and_then(|poetry| poetry.get_mut("version"))
+        .is_some_and(|item| set_toml_version(item, version));
+
+    ensure!(
+        project || poetry,
+        "no `project.version` or `tool.poetry.version` found"
+    );
+    Ok(document.to_string())
+}
+
+// The first string value found at one of the given key paths.
+fn toml_version(content: &str, paths: &[&[&str]]) -> Result<String> {
+    let document: DocumentMut = content.parse()?;
+    paths
+        .iter()
+        .find_map(|path| {
+            path.iter()
+                .try_fold(document.as_item(), |item, key| item.get(key))
+                .and_then(Item::as_str)
+        })
+        .map(str::to_string)
+        .ok_or_else(|| {
+            let keys = paths
+                .iter()
+                .map(|path| format!("`{}`", path.join(".")))
+                .join(" or ");
+            anyhow!("no {keys} found")
+        })
+}
+
+// Replace a string value, keeping its surrounding whitespace and comments.
+// Inherited versions such as `version.workspace = true` are left untouched.
+fn set_toml_version(item: &mut Item, version: &str) -> bool {
+    match item.as_value_mut() {
+        Some(Value::String(value)) => {
+            let decor = value.decor().clone();
+            *value = Formatted::new(version.to_string());
+            *value.decor_mut() = decor;
+            true
+        }
+        _ => false,
+    }
+}
+
+fn update_package_json(content: &str, version: &str) -> Result<String> {
+    Ok(replace_range(
+        content,
+        package_json_version(content)?,
+        version,
+    ))
+}
+
+// The range of the top level `"version"` string value.
+fn package_json_version(content: &str) -> Result<Range<usize>> {
+    let json: serde_json::Value = serde_json::from_str(content)?;
+    ensure!(
+        json.get("version")
+            .is_some_and(serde_json::Value::is_string),
+        "no top level `version` found"
+    );
+
+    // Locate the top level `"version"` key without reformatting the document.
+    let bytes = content.as_bytes();
+    let mut depth = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth -= 1,
+            b'"' => {
+                let end = json_string_end(bytes, index);
+                let after_key = skip_json_whitespace(bytes, end + 1);
+                let is_key = bytes.get(after_key) == Some(&b':');
+
+                if depth == 1 && is_key && &content[index + 1..end] == "version" {
+                    let start = skip_json_whitespace(bytes, after_key + 1);
+                    let end = json_string_end(bytes, start);
+                    return Ok(start + 1..end);
+                }
+
+                index = end;
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    bail!("no top level `version` found")
+}
+
+// Index of the closing quote of the json string starting at `start`.
+fn json_string_end(bytes: &[u8], start: usize) -> usize {
+    let mut index = start + 1;
+    while index < bytes.len() && bytes[index] != b'"' {
+        if bytes[index] == b'\\' {
+            index += 1;
+        }
+        index += 1;
+    }
+
+    index
+}
+
+fn skip_json_whitespace(bytes: &[u8], mut index: usize) -> usize {
+    while bytes.get(index).is_some_and(u8::is_ascii_whitespace) {
+        index += 1;
+    }
+
+    index
+}
+
+fn update_pom(content: &str, version: &str) -> Result<String> {
+    Ok(replace_range(content, pom_version(content)?, version))
+}
+
+// The range of the project `<version>` element text.
+fn pom_version(content: &str) -> Result<Range<usize>> {
+    let tags = Regex::new(
+        r"<!--[\s\S]*?-->|<\?[\s\S]*?\?>|<!\[CDATA\[[\s\S]*?\]\]>|<(/?)([\w.:-]+)[^>]*?(/?)>",
+    )?;
+    let mut elements: Vec<&str> = vec![];
+
+    for captures in tags.captures_iter(content) {
+        let Some(name) = captures.get(2) else {
+            continue;
+        };
+
+        if !captures[1].is_empty() {
+            elements.pop();
+            continue;
+        }
+
+        if !captures[3].is_empty() {
+            continue;
+        }
+
+        // `<version>` in `<parent>` or `<dependencies>` is not the project version
+        if name.as_str() == "version" && elements == ["project"] {
+            let start = captures.get(0).expect("regex match").end();
+            let end = content[start..]
+                .find("</version>")
+                .map(|end| start + end)
+                .ok_or_else(|| anyhow!("unclosed `<version>` element"))?;
+
+            return Ok(start..end);
+        }
+
+        elements.push(name.as_str());
+    }
+
+    bail!("no project `<version>` found")
+}
+
+// The file content without surrounding whitespace.
+fn plain_version(content: &str) -> Result<Range<usize>> {
+    let start = content.len() - content.trim_start().len();
+    let end = content.trim_end().len();
+    ensure!(start < end, "the version file is empty");
+    Ok(start..end)
+}
+
+fn replace_range(content: &str, range: Range<usize>, version: &str) -> String {
+    format!(
+        "{}{version}{}",
+        &content[..range.start],
+        &content[range.end..]
+    )
+}
+
+// The first match of the `version` named group, the first capture group, or the whole match.
+fn regex_version(content: &str, regex: &Regex) -> Result<String> {
+    let captures = regex
+        .captures(content)
+        .ok_or_else(|| anyhow!("pattern '{}' does not match", regex.as_str()))?;
+    let matched = captures
+        .name("version")
+        .or_else(|| captures.get(1))
+        .unwrap_or_else(|| captures.get(0).expect("regex match"));
+
+    Ok(matched.as_str().to_string())
+}
+
+fn update_regex(content: &str, regex: &Regex, version: &str) -> Result<String> {
+    let mut updated = String::with_capacity(content.len());
+    let mut last = 0;
+
+    for captures in regex.captures_iter(content) {
+        let matched = captures
+            .name("version")
+            .or_else(|| captures.get(1))
+            .unwrap_or_else(|| captures.get(0).expect("regex match"));
+
+        updated.push_str(&content[last..matched.start()]);
+        updated.push_str(version);
+        last = matched.end();
+    }
+
+    ensure!(last > 0, "pattern '{}' does not match", regex.as_str());
+    updated.push_str(&content[last..]);
+    Ok(updated)
+}
+
+fn line_regex(line: &str) -> Result<Regex> {
+    let Some((before, after)) = line.split_once("{version}") else {
+        bail!("line pattern '{line}' does not contain `{{version}}`");
+    };
+
+    Ok(Regex::new(&format!(
+        r"(?m)^[ \t]*{}(?<version>[^\s]+?){}[ \t]*\r?$",
+        regex::escape(before.trim_start()),
+        regex::escape(after.trim_end())
+    ))?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_update_cargo_package_and_workspace_versions() -> Result<()> {
+        let cargo = indoc! {r#"
+            [package]
+            name = "cog"
+            version = "0.1.0" # the version
+
+            [workspace.package]
+            version = "0.1.0"
+
+            [dependencies]
+            serde = { version = "1" }
+        "#};
+
+        assert_eq!(
+            update_cargo(cargo, "1.0.0")?,
+            indoc! {r#"
+                [package]
+                name = "cog"
+                version = "1.0.0" # the version
+
+                [workspace.package]
+                version = "1.0.0"
+
+                [dependencies]
+                serde = { version = "1" }
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_on_inherited_cargo_version() {
+        let cargo = "[package]\nname = \"cog\"\nversion.workspace = true\n";
+
+        assert_that!(update_cargo(cargo, "1.0.0")).is_err();
+    }
+
+    #[test]
+    fn should_update_package_json_top_level_version() -> Result<()> {
+        let package = indoc! {r#"
+            {
+              "name": "version",
+              "engines": { "version": "18" },
+              "version": "0.1.0",
+              "dependencies": {
+                "left-pad": "1.0.0"
+              }
+            }
+        "#};
+
+        assert_eq!(
+            update_package_json(package, "1.0.0")?,
+            indoc! {r#"
+                {
+                  "name": "version",
+                  "engines": { "version": "18" },
+                  "version": "1.0.0",
+                  "dependencies": {
+                    "left-pad": "1.0.0"
+                  }
+                }
+            "#}
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_update_pom_project_version() -> Result<()> {
+        let pom = indoc! {r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <project>
+              <parent>
+                <version>2.0.0</version>
+              </parent>
+              <!-- <version>commented</version> -->
+              <artifactId>cog</artifactId>
+              <version>0.1.0</version>
+              <dependencies>
+                <dependency><version>3.0.0</version></dependency>
+              </dependencies>
+            </project>
+        "#};
+
+        let updated = update_pom(pom, "1.0.0")?;
+
+        assert_eq!(updated, pom.replace("<version>0.1.0", "<version>1.0.0"));
+        Ok(())
+    }
+
+    #[test]
+    fn should_update_pyproject_version() -> Result<()> {
+        let pyproject = "[tool.poetry]\nname = \"cog\"\nversion = \"0.1.0\"\n";
+
+        assert_eq!(
+            update_pyproject(pyproject, "1.0.0")?,
+            "[tool.poetry]\nname = \"cog\"\nversion = \"1.0.0\"\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_update_regex_and_line_patterns() -> Result<()> {
+        let readme = "cog = \"0.1.0\"\nother = \"0.1.0\"\n";
+
+        let by_line = update_regex(readme, &line_regex("cog = \"{version}\"")?, "1.0.0")?;
+        let by_regex = update_regex(readme, &Regex::new(r#"other = "([^"]+)""#)?, "1.0.0")?;
+
+        assert_eq!(by_line, "cog = \"1.0.0\"\nother = \"0.1.0\"\n");
+        assert_eq!(by_regex, "cog = \"0.1.0\"\nother = \"1.0.0\"\n");
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_when_pattern_does_not_match() -> Result<()> {
+        assert_that!(update_regex(
+            "nothing",
+            &Regex::new("version=(.*)")?,
+            "1.0.0"
+        ))
+        .is_err();
+        Ok(())
+    }
+
+    #[test]
+    fn should_infer_format_from_file_name() {
+        let version_file = |path: &str| VersionFile {
+            path: PathBuf::from(path),
+            ..Default::default()
+        };
+
+        assert_that!(version_file("crates/cog/Cargo.toml").format().ok())
+            .is_equal_to(Some(VersionFileFormat::Cargo));
+        assert_that!(version_file("package.json").format().ok())
+            .is_equal_to(Some(VersionFileFormat::PackageJson));
+        assert_that!(version_file("VERSION").format().ok())
+            .is_equal_to(Some(VersionFileFormat::Plain));
+        assert_that!(version_file("README.md").format()).is_err();
+    }
+
+    #[test]
+    fn should_read_current_version() -> Result<()> {
+        let version_file = |path: &str| VersionFile {
+            path: PathBuf::from(path),
+            ..Default::default()
+        };
+        let pom =
+            "<project><parent><version>2.0.0</version></parent><version>0.1.0</version></project>";
+
+        assert_eq!(
+            version_file("Cargo.toml")
+                .current_version("[workspace.package]\nversion = \"0.1.0\"\n")?,
+            "0.1.0"
+        );
+        assert_eq!(
+            version_file("package.json")
+                .current_version(r#"{ "engines": { "version": "18" }, "version": "0.2.0" }"#)?,
+            "0.2.0"
+        );
+        assert_eq!(version_file("pom.xml").current_version(pom)?, "0.1.0");
+        assert_eq!(version_file("VERSION").current_version("1.2.3\n")?, "1.2.3");
+        assert_that!(version_file("VERSION").current_version("\n")).is_err();
+        Ok(())
+    }
+
+    #[test]
+    fn should_update_plain_version_file() -> Result<()> {
+        assert_eq!(
+            replace_range("0.1.0\n", plain_version("0.1.0\n")?, "1.0.0"),
+            "1.0.0\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_render_changed_lines_as_diff() {
+        let update = VersionFileUpdate {
+            path: PathBuf::from("Cargo.toml"),
+            file: PathBuf::from("/repo/Cargo.toml"),
+            previous: "[package]\nversion = \"0.1.0\"\n".to_string(),
+            updated: "[package]\nversion = \"1.0.0\"\n".to_string(),
+        };
+
+        assert_eq!(
+            update.diff(),
+            "--- a/Cargo.toml\n+++ b/Cargo.toml\n@@ -2 +2 @@\n-version = \"0.1.0\"\n+version = \"1.0.0\"\n"
+        );
+    }
+}
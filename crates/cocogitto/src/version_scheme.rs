@@ -0,0 +1,393 @@
+use std::fmt::Write;
+
+use chrono::{Datelike, NaiveDate};
+use regex::Regex;
+use semver::{BuildMetadata, Prerelease, Version};
+use serde::{Deserialize, Serialize};
+
+/// Default calendar versioning format.
+pub const DEFAULT_CALVER_FORMAT: &str = "YYYY.0M.MICRO";
+
+/// # VersionSchemeKind
+/// How versions are formatted, parsed and incremented.
+#[cfg_attr(feature = "docgen", derive(cog_schemars::JsonSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionSchemeKind {
+    /// Semantic versioning, `MAJOR.MINOR.PATCH`
+    #[default]
+    Semver,
+    /// Calendar versioning, formatted with `calver_format`
+    Calver,
+}
+
+/// The version scheme of a package or of the global version.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VersionScheme<'a> {
+    SemVer,
+    CalVer(&'a CalVerFormat),
+}
+
+impl VersionScheme<'_> {
+    /// Parse a version, without tag prefix or package name.
+    pub fn parse(&self, raw: &str) -> Option<Version> {
+        match self {
+            VersionScheme::SemVer => Version::parse(raw).ok(),
+            VersionScheme::CalVer(format) => format.parse(raw),
+        }
+    }
+
+    pub fn format(&self, version: &Version) -> String {
+        match self {
+            VersionScheme::SemVer => version.to_string(),
+            VersionScheme::CalVer(format) => format.format(version),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Token {
+    /// `YYYY`: 2006, 2016, 2106
+    FullYear,
+    /// `YY`: 6, 16, 106
+    ShortYear,
+    /// `0Y`: 06, 16, 106
+    PaddedYear,
+    /// `MM`: 1, 2 ... 11, 12
+    Month,
+    /// `0M`: 01, 02 ... 11, 12
+    PaddedMonth,
+    /// `WW`: 1, 2 ... 33, 52
+    Week,
+    /// `0W`: 01, 02 ... 33, 52
+    PaddedWeek,
+    /// `DD`: 1, 2 ... 30, 31
+    Day,
+    /// `0D`: 01, 02 ... 30, 31
+    PaddedDay,
+    /// `MICRO`: incremented on each release of the same period, reset when the period changes
+    Micro,
+}
+
+impl Token {
+    const ALL: [(&'static str, Token); 10] = [
+        ("YYYY", Token::FullYear),
+        ("YY", Token::ShortYear),
+        ("0Y", Token::PaddedYear),
+        ("MM", Token::Month),
+        ("0M", Token::PaddedMonth),
+        ("WW", Token::Week),
+        ("0W", Token::PaddedWeek),
+        ("DD", Token::Day),
+        ("0D", Token::PaddedDay),
+        ("MICRO", Token::Micro),
+    ];
+
+    fn pattern(self) -> &'static str {
+        match self {
+            Token::FullYear => r"(\d{4})",
+            Token::ShortYear => r"(\d{1,3})",
+            Token::PaddedYear => r"(\d{2,3})",
+            Token::Month | Token::Week | Token::Day => r"(\d{1,2})",
+            Token::PaddedMonth | Token::PaddedWeek | Token::PaddedDay => r"(\d{2})",
+            Token::Micro => r"(\d+)",
+        }
+    }
+
+    fn format(self, value: u64) -> String {
+        match self {
+            Token::PaddedYear | Token::PaddedMonth | Token::PaddedWeek | Token::PaddedDay => {
+                format!("{value:02}")
+            }
+            _ => value.to_string(),
+        }
+    }
+
+    /// The value of a date token for the given day, `None` for `MICRO`. Formats with a week
+    /// token use the ISO week-numbering year, the first days of January possibly belonging
+    /// to the last week of the previous year and the last days of December to the first week
+    /// of the next one.
+    fn value(self, date: NaiveDate, iso_week_year: bool) -> Option<u64> {
+        let year = if iso_week_year {
+            date.iso_week().year()
+        } else {
+            date.year()
+        } as u64;
+
+        let value = match self {
+            Token::FullYear => year,
+            Token::ShortYear | Token::PaddedYear => year - 2000,
+            Token::Month | Token::PaddedMonth => date.month() as u64,
+            Token::Week | Token::PaddedWeek => date.iso_week().week() as u64,
+            Token::Day | Token::PaddedDay => date.day() as u64,
+            Token::Micro => return None,
+        };
+
+        Some(value)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Segment {
+    Token(Token),
+    Literal(char),
+}
+
+/// A calendar versioning format such as `YYYY.0M.MICRO`, see <https://calver.org>.
+///
+/// Versions are stored as semver versions, the format tokens being mapped in order
+/// to the major, minor and patch components.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CalVerFormat {
+    format: String,
+    segments: Vec<Segment>,
+    regex: Regex,
+}
+
+impl PartialEq for CalVerFormat {
+    fn eq(&self, other: &Self) -> bool {
+        self.format == other.format
+    }
+}
+
+impl Eq for CalVerFormat {}
+
+impl TryFrom<String> for CalVerFormat {
+    type Error = String;
+
+    fn try_from(format: String) -> Result<Self, Self::Error> {
+        CalVerFormat::parse_format(&format)
+    }
+}
+
+impl From<CalVerFormat> for String {
+    fn from(format: CalVerFormat) -> Self {
+        format.format
+    }
+}
+
+impl Default for CalVerFormat {
+    fn default() -> Self {
+        CalVerFormat::parse_format(DEFAULT_CALVER_FORMAT).expect("valid default calver format")
+    }
+}
+
+impl CalVerFormat {
+    /// Parse a format made of one or two date tokens followed by `MICRO`.
+    pub fn parse_format(format: &str) -> Result<Self, String> {
+        let mut segments = vec![];
+        let mut remains = format;
+        while let Some(char) = remains.chars().next() {
+            match Token::ALL
+                .iter()
+                .find(|(token, _)| remains.starts_with(token))
+            {
+                Some((raw, token)) => {
+                    segments.push(Segment::Token(*token));
+                    remains = &remains[raw.len()..];
+                }
+                None => {
+                    segments.push(Segment::Literal(char));
+                    remains = &remains[char.len_utf8()..];
+                }
+            }
+        }
+
+        let tokens: Vec<Token> = segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Token(token) => Some(*token),
+                Segment::Literal(_) => None,
+            })
+            .collect();
+
+        match tokens.split_last() {
+            Some((Token::Micro, date)) if (1..=2).contains(&date.len()) && !date.contains(&Token::Micro) => {}
+            _ => {
+                return Err(format!(
+                    "invalid calver format '{format}', expected one or two date tokens followed by MICRO"
+                ))
+            }
+        }
+
+        let mut regex = String::from("^");
+        for segment in &segments {
+            match segment {
+                Segment::Token(token) => regex.push_str(token.pattern()),
+                Segment::Literal(char) => regex.push_str(&regex::escape(&char.to_string())),
+            }
+        }
+        regex.push_str(r"(?:-([0-9A-Za-z.-]+))?(?:\+([0-9A-Za-z.-]+))?$");
+        let regex =
+            Regex::new(&regex).map_err(|err| format!("invalid calver format '{format}': {err}"))?;
+
+        Ok(CalVerFormat {
+            format: format.to_string(),
+            segments,
+            regex,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.format
+    }
+
+    fn tokens(&self) -> impl Iterator<Item = Token> + '_ {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Token(token) => Some(*token),
+            Segment::Literal(_) => None,
+        })
+    }
+
+    pub fn parse(&self, raw: &str) -> Option<Version> {
+        let captures = self.regex.captures(raw)?;
+        let token_count = self.tokens().count();
+
+        let mut components = [0; 3];
+        for (idx, component) in components.iter_mut().take(token_count).enumerate() {
+            *component = captures.get(idx + 1)?.as_str().parse().ok()?;
+        }
+
+        let mut version = Version::new(components[0], components[1], components[2]);
+        if let Some(pre) = captures.get(token_count + 1) {
+            version.pre = Prerelease::new(pre.as_str()).ok()?;
+        }
+        if let Some(build) = captures.get(token_count + 2) {
+            version.build = BuildMetadata::new(build.as_str()).ok()?;
+        }
+
+        Some(version)
+    }
+
+    pub fn format(&self, version: &Version) -> String {
+        let mut components = [version.major, version.minor, version.patch].into_iter();
+        let mut output = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Token(token) => {
+                    output.push_str(&token.format(components.next().unwrap_or_default()))
+                }
+                Segment::Literal(char) => output.push(*char),
+            }
+        }
+
+        if !version.pre.is_empty() {
+            let _ = write!(output, "-{}", version.pre);
+        }
+        if !version.build.is_empty() {
+            let _ = write!(output, "+{}", version.build);
+        }
+
+        output
+    }
+
+    /// The next version released on the given day: `MICRO` is incremented if the current
+    /// version belongs to the same period, and reset otherwise.
+    pub fn bump(&self, current: &Version, date: NaiveDate) -> Version {
+        let current_components = [current.major, current.minor, current.patch];
+        let mut components = [0; 3];
+        let mut same_period = true;
+        let iso_week_year = self
+            .tokens()
+            .any(|token| matches!(token, Token::Week | Token::PaddedWeek));
+
+        for (idx, token) in self.tokens().enumerate() {
+            components[idx] = match token.value(date, iso_week_year) {
+                Some(value) => {
+                    same_period &= value == current_components[idx];
+                    value
+                }
+                None if same_period => current_components[idx] + 1,
+                None => 0,
+            };
+        }
+
+        Version::new(components[0], components[1], components[2])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use semver::Version;
+    use speculoos::prelude::*;
+
+    use super::CalVerFormat;
+
+    #[test]
+    fn should_parse_and_format_calver() {
+        let format = CalVerFormat::parse_format("YYYY.0M.MICRO").unwrap();
+
+        let version = format.parse("2024.05.2").unwrap();
+
+        assert_that!(version).is_equal_to(Version::new(2024, 5, 2));
+        assert_that!(format.format(&version)).is_equal_to("2024.05.2".to_string());
+    }
+
+    #[test]
+    fn should_parse_and_format_calver_with_pre_release() {
+        let format = CalVerFormat::parse_format("YY.MM.MICRO").unwrap();
+
+        let version = format.parse("24.5.0-rc.1").unwrap();
+
+        assert_that!(version).is_equal_to(Version::parse("24.5.0-rc.1").unwrap());
+        assert_that!(format.format(&version)).is_equal_to("24.5.0-rc.1".to_string());
+    }
+
+    #[test]
+    fn should_not_parse_version_not_matching_format() {
+        let format = CalVerFormat::parse_format("YYYY.0M.MICRO").unwrap();
+
+        assert_that!(format.parse("2024.5.2")).is_none();
+        assert_that!(format.parse("1.0.0")).is_none();
+    }
+
+    #[test]
+    fn should_increment_micro_in_same_period() {
+        let format = CalVerFormat::parse_format("YYYY.0M.MICRO").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 5, 20).unwrap();
+
+        let next = format.bump(&Version::new(2024, 5, 2), date);
+
+        assert_that!(next).is_equal_to(Version::new(2024, 5, 3));
+    }
+
+    #[test]
+    fn should_reset_micro_when_period_changes() {
+        let format = CalVerFormat::parse_format("YYYY.0M.MICRO").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+
+        let next = format.bump(&Version::new(2024, 5, 2), date);
+
+        assert_that!(next).is_equal_to(Version::new(2024, 6, 0));
+    }
+
+    #[test]
+    fn should_bump_week_format() {
+        let format = CalVerFormat::parse_format("YY.0W.MICRO").unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+
+        let next = format.bump(&Version::new(23, 52, 4), date);
+
+        assert_that!(format.format(&next)).is_equal_to("24.02.0".to_string());
+    }
+
+    #[test]
+    fn should_bump_week_format_with_iso_week_year() {
+        let format = CalVerFormat::parse_format("YYYY.0W.MICRO").unwrap();
+        // 2024-12-30 belongs to the first week of 2025
+        let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+
+        let next = format.bump(&Version::new(2024, 52, 1), date);
+
+        assert_that!(format.format(&next)).is_equal_to("2025.01.0".to_string());
+    }
+
+    #[test]
+    fn should_reject_invalid_formats() {
+        assert_that!(CalVerFormat::parse_format("YYYY.0M")).is_err();
+        assert_that!(CalVerFormat::parse_format("MICRO.YYYY")).is_err();
+        assert_that!(CalVerFormat::parse_format("YYYY.MM.DD.MICRO")).is_err();
+    }
+}
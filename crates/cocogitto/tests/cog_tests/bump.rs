@@ -5,8 +5,11 @@ use crate::helpers::*;
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use cmd_lib::run_cmd;
+use cocogitto::conventional::bump_rule::BumpRule;
+use cocogitto::conventional::version::Increment;
 use cocogitto::settings::Settings;
 use indoc::indoc;
+use predicates::prelude::{predicate, PredicateBooleanExt};
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
 use std::path::Path;
@@ -522,833 +525,2770 @@ fn package_dry_run() -> Result<()> {
 }
 
 #[sealed_test]
-fn uncommitted_changes_should_throw_error_by_default() -> Result<()> {
+fn bump_dry_run_json_plan() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"pre_bump_hooks = ["echo {{latest}} -> {{version}}"]
+
+            [[version_files]]
+            path = "VERSION"
+            line = "{version}"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_add("1.0.0\n", "VERSION")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("docs: readme")?;
+    let feature = git_commit("feat(api): new endpoint")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success();
+
+    let plan: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)?;
+    assert_eq!(plan["schema_version"], 1);
+    assert_eq!(plan["commit_message"], "chore(version): 1.1.0");
+    assert_eq!(plan["tags"], serde_json::json!(["1.1.0"]));
+
+    let version = &plan["versions"][0];
+    assert_eq!(version["current"], "1.0.0");
+    assert_eq!(version["next"], "1.1.0");
+    assert_eq!(version["increment"], "minor");
+    assert_eq!(version["commits"].as_array().unwrap().len(), 1);
+    assert_eq!(version["commits"][0]["id"], &feature[0..6]);
+    assert_eq!(version["commits"][0]["summary"], "feat(api): new endpoint");
+    assert_eq!(
+        version["pre_bump_hooks"],
+        serde_json::json!(["echo 1.0.0 -> 1.1.0"])
+    );
+    assert_eq!(version["version_files"][0]["path"], "VERSION");
+    assert_that!(version["version_files"][0]["diff"].as_str().unwrap()).contains("+1.1.0");
+    assert_that!(version["changelog"].as_str().unwrap()).contains("new endpoint");
+
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_that!(std::fs::read_to_string("VERSION")?).is_equal_to("1.0.0\n".to_string());
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_dry_run_json_plan_lists_pre_release_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let feature = git_commit("feat: feature")?;
+    git_tag("1.1.0-rc.1")?;
+    let fix = git_commit("fix: bug fix")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success();
+
+    let plan: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)?;
+    let version = &plan["versions"][0];
+    assert_eq!(version["next"], "1.1.0");
+    assert_eq!(version["commits"][0]["id"], &fix[0..6]);
+    assert_eq!(version["commits"][1]["id"], &feature[0..6]);
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_dry_run_text_plan() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("text")
+        .arg("--disable-bump-commit")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("version: 1.0.0 -> 1.0.1 (patch)"))
+        .stdout(predicate::str::contains("fix: bug fix (patch)"))
+        .stdout(predicate::str::contains("commit: disabled"))
+        .stdout(predicate::str::contains("tags: 1.0.1"));
+
+    assert_tag_does_not_exist("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_format_requires_dry_run() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .failure();
+
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_explain_text_plan() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[commit_types]
+            perf = { changelog_title = "Performance", bump_minor = true }
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let docs = git_commit("docs: readme")?;
+    let fix = git_commit("fix: bug fix")?;
+    let perf = git_commit("perf: faster parser")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--explain")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "{} docs: readme: none (`docs` commits do not bump the version)",
+            &docs[0..6]
+        )))
+        .stdout(predicate::str::contains(format!(
+            "{} fix: bug fix: patch (`fix` commits bump the patch version)",
+            &fix[0..6]
+        )))
+        .stdout(predicate::str::contains(format!(
+            "{} perf: faster parser: minor (`perf` commits bump the minor version)",
+            &perf[0..6]
+        )))
+        .stdout(predicate::str::contains("highest commit increment: minor"))
+        .stdout(predicate::str::contains(
+            "next version: 1.0.0 -> 1.1.0 (minor)",
+        ));
+
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_explain_includes_pre_release_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let feat = git_commit("feat: feature")?;
+    git_tag("1.1.0-rc.1")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--explain")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "{} feat: feature: minor",
+            &feat[0..6]
+        )))
+        .stdout(predicate::str::contains("highest commit increment: minor"))
+        .stdout(predicate::str::contains(
+            "next version: 1.0.0 -> 1.1.0 (minor)",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_explain_json_plan() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    let breaking = git_commit("fix: bug fix\n\nBREAKING CHANGE: removed the old api")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("alpha.1")
+        .arg("--explain")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success();
+
+    let plan: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)?;
+    let explanation = &plan["versions"][0]["explanation"];
+    assert_eq!(explanation["commits"][0]["id"], &breaking[0..6]);
+    assert_eq!(explanation["commits"][0]["increment"], "major");
+    assert_eq!(
+        explanation["commits"][0]["reason"],
+        "BREAKING CHANGE footer"
+    );
+    assert_eq!(
+        explanation["decision"],
+        serde_json::json!([
+            "highest commit increment: major",
+            "pre-release `alpha.1`",
+            "next version: 1.0.0 -> 2.0.0-alpha.1 (major)"
+        ])
+    );
+
+    assert_tag_does_not_exist("2.0.0-alpha.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn monorepo_explain_package_propagation() -> Result<()> {
+    // Arrange
+    init_monorepo(&mut Settings::default())?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--explain")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("one: minor (public api)"))
+        .stdout(predicate::str::contains(
+            "highest public api package increment: minor",
+        ))
+        .stdout(predicate::str::contains(
+            "next version: none -> 0.1.0 (minor)",
+        ));
+
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn monorepo_dry_run_json_plan() -> Result<()> {
+    // Arrange
     init_monorepo(&mut Settings::default())?;
 
-    run_cmd!(
-        echo two > two;
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success();
+
+    let plan: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)?;
+    assert_eq!(plan["tags"], serde_json::json!(["one-0.1.0", "0.1.0"]));
+
+    let versions = plan["versions"].as_array().unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0]["package"], "one");
+    assert_eq!(versions[0]["current"], serde_json::Value::Null);
+    assert_eq!(versions[0]["next"], "one-0.1.0");
+    assert_eq!(versions[1]["package"], serde_json::Value::Null);
+    assert_eq!(versions[1]["next"], "0.1.0");
+
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_with_bump_rules() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[[bump_rules]]
+            type = "fix"
+            scope = "deps"
+            increment = "none"
+
+            [[bump_rules]]
+            type = "feat"
+            scope = "internal"
+            increment = "patch"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("fix(deps): bump serde")?;
+    git_commit("feat(internal): faster cache")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.0.1\n");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_with_bump_rules_skips_dependency_updates() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[[bump_rules]]
+            type = "fix"
+            scope = "deps"
+            increment = "none"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("fix(deps): bump serde")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No conventional commits"));
+
+    assert_tag_does_not_exist("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_with_release_as_footer_rule() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[[bump_rules]]
+            footer = "Release-As: major"
+            increment = "major"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("chore: release\n\nRelease-As: major")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("2.0.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_with_major_bump_rule_bumps_minor_on_zero_major() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[[bump_rules]]
+            footer = "Release-As: major"
+            increment = "major"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("0.3.0")?;
+    git_commit("chore: release\n\nRelease-As: major")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("0.4.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn package_auto_bump_with_bump_rules() -> Result<()> {
+    // Arrange
+    let mut settings = Settings {
+        bump_rules: vec![BumpRule {
+            commit_type: Some("feat".to_string()),
+            scope: Some("internal".to_string()),
+            footer: None,
+            body: None,
+            increment: Increment::Patch,
+        }],
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+    git_tag("one-0.1.0")?;
+    git_add("two", "one/two")?;
+    git_commit("feat(internal): faster cache")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--package")
+        .arg("one")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("one-0.1.1\n");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_rule_without_condition_is_rejected() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[[bump_rules]]\nincrement = \"major\"\n", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid bump rule"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_initial_development_breaking_change_bumps_minor() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("initial_development = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("0.1.0")?;
+    git_commit("feat!: breaking feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Initial development policy for 0.1.0: major changes bump the minor version",
+        ));
+
+    assert_tag_exists("0.2.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_initial_development_feature_bumps_patch() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("initial_development = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("0.1.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("0.1.1\n");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_initial_development_does_not_apply_to_stable_versions() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("initial_development = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat!: breaking feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("2.0.0\n");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn package_auto_bump_initial_development_override() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[monorepo.packages.one]
+            path = "one"
+            initial_development = true
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_add("one", "one/file")?;
+    git_commit("chore: init")?;
+    git_tag("one-0.1.0")?;
+    git_tag("0.1.0")?;
+    git_add("two", "one/two")?;
+    git_commit("feat: package feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--package")
+        .arg("one")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("one-0.1.1\n");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn major_bump_is_refused_during_initial_development() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("initial_development = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("0.3.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--major")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cog bump --graduate"));
+
+    assert_tag_does_not_exist("1.0.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn graduate_bump_releases_1_0_0() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("initial_development = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("0.3.2")?;
+    git_commit("feat: stable api")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--graduate")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.0.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn graduate_bump_fails_on_stable_version() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.2.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--graduate")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already stable"));
+
+    assert_tag_does_not_exist("2.0.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn uncommitted_changes_should_throw_error_by_default() -> Result<()> {
+    init_monorepo(&mut Settings::default())?;
+
+    run_cmd!(
+        echo two > two;
+    )?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn uncommitted_changes_should_not_throw_error_with_option() -> Result<()> {
+    let mut settings = Settings {
+        skip_untracked: true,
+        ..Default::default()
+    };
+
+    init_monorepo(&mut settings)?;
+
+    run_cmd!(
+        echo two > two;
+        echo "other changes" > one/file;
+    )?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stderr(indoc!("Untracked files :\n\tmodified: one/file\n\tnew: two\n\nnothing added to commit but untracked files present (use \"git add\" to track)\n\n"))
+        .stdout(indoc!("one-0.1.0\n0.1.0\n"));
+
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_package_with_default_skip_ci_ok() -> Result<()> {
+    let mut settings = Settings {
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        .arg("--package")
+        .arg("one")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_exists("one-0.1.0")?;
+    assert!(commit_message.contains("[skip ci]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_package_with_cog_toml_defined_skip_ci_ok() -> Result<()> {
+    let mut settings = Settings {
+        skip_ci: String::from("[ci-skip]"),
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        .arg("--package")
+        .arg("one")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_exists("one-0.1.0")?;
+    assert!(commit_message.contains("[ci-skip]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_package_with_skip_ci_override_option_takes_precedence() -> Result<()> {
+    let mut settings = Settings {
+        skip_ci: String::from("[ci-skip]"),
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci-override")
+        .arg("[ci-skip-override]")
+        .arg("--package")
+        .arg("one")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_exists("one-0.1.0")?;
+    assert!(commit_message.contains("[ci-skip-override]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_standard_repository_with_default_skip_ci_ok() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--skip-ci")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+    assert_tag_exists("0.1.0")?;
+    assert!(commit_message.contains("[skip ci]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_standard_repository_with_cog_toml_defined_skip_ci_ok() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_add("skip_ci = \"[ci-skip]\"", "cog.toml")?;
+    git_commit("feat: feature")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--skip-ci")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+    assert_tag_exists("0.1.0")?;
+    assert!(commit_message.contains("[ci-skip]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_standard_repository_skip_ci_override_option_takes_precedence() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_add("skip_ci = \"[ci-skip]\"", "cog.toml")?;
+    git_commit("feat: feature")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--skip-ci-override")
+        .arg("[ci-skip-override]")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+    assert_tag_exists("0.1.0")?;
+    assert!(commit_message.contains("[ci-skip-override]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_monorepo_with_default_skip_ci_ok() -> Result<()> {
+    let mut settings = Settings {
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_exists("0.1.0")?;
+    assert!(commit_message.contains("[skip ci]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_monorepo_manual_increment_with_default_skip_ci_ok() -> Result<()> {
+    let mut settings = Settings {
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--minor")
+        .arg("--skip-ci")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_exists("0.1.0")?;
+    assert!(commit_message.contains("[skip ci]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_monorepo_with_cog_toml_defined_skip_ci_ok() -> Result<()> {
+    let mut settings = Settings {
+        skip_ci: String::from("[ci-skip]"),
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_exists("0.1.0")?;
+    assert!(commit_message.contains("[ci-skip]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_monorepo_skip_ci_override_option_takes_precedence() -> Result<()> {
+    let mut settings = Settings {
+        skip_ci: String::from("[ci-skip]"),
+        ..Default::default()
+    };
+
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci-override")
+        .arg("[skip-ci-override]")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_exists("0.1.0")?;
+    assert!(commit_message.contains("[skip-ci-override]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_only_package_with_default_skip_ci_ok() -> Result<()> {
+    let mut settings = Settings {
+        generate_mono_repository_global_tag: false,
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--skip-ci")
+        .assert()
+        .success();
+
+    let commit_message = git_log_head_message()?;
+
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_tag_exists("one-0.1.0")?;
+    assert!(commit_message.contains("[skip ci]"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn disable_commit_creation_with_config_standard_ok() -> Result<()> {
+    git_init()?;
+
+    git_add("disable_bump_commit = true", "cog.toml")?;
+
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+
+    Command::new("git")
+        .arg("status")
+        .arg("-s")
+        .assert()
+        .success()
+        .stdout(indoc!("A  CHANGELOG.md\n"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn disable_commit_creation_with_flag_standard_ok() -> Result<()> {
+    git_init()?;
+
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--disable-bump-commit")
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+
+    Command::new("git")
+        .arg("status")
+        .arg("-s")
+        .assert()
+        .success()
+        .stdout(indoc!("A  CHANGELOG.md\n"));
+
+    Ok(())
+}
+
+#[sealed_test]
+#[cfg(target_os = "linux")]
+fn disable_commit_creation_with_pre_bump_hooks_standard_ok() -> Result<()> {
+    git_init()?;
+
+    git_add(
+        "pre_bump_hooks = [\"echo pre_bump_file > pre_bump_file\"]\ndisable_bump_commit = true",
+        "cog.toml",
+    )?;
+
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--disable-bump-commit")
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+
+    Command::new("git")
+        .arg("status")
+        .arg("-s")
+        .assert()
+        .success()
+        .stdout(indoc!("A  CHANGELOG.md\nA  pre_bump_file\n"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn override_default_commit() -> Result<()> {
+    git_init()?;
+
+    git_add(
+        indoc! {
+          r#"[commit_types]
+          feat = { changelog_title = "🌟 Features" }
+          fix = { changelog_title = "🐛 Bug Fixes" }"#
+        },
+        "cog.toml",
+    )?;
+
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+    git_commit("fix: fix")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn disable_commit_creation_monorepo_ok() -> Result<()> {
+    let mut settings = Settings {
+        disable_bump_commit: true,
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+    assert_tag_exists("one-0.1.0")?;
+
+    Command::new("git")
+        .arg("status")
+        .arg("-s")
+        .assert()
+        .success()
+        .stdout(indoc!("A  CHANGELOG.md\nA  one/CHANGELOG.md\n"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn disable_commit_creation_package_ok() -> Result<()> {
+    let mut settings = Settings {
+        disable_bump_commit: true,
+        ..Default::default()
+    };
+    init_monorepo(&mut settings)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--package")
+        .arg("one")
+        .arg("--disable-bump-commit")
+        .assert()
+        .success();
+
+    assert_tag_exists("one-0.1.0")?;
+
+    Command::new("git")
+        .arg("status")
+        .arg("-s")
+        .assert()
+        .success()
+        .stdout(indoc!("A  one/CHANGELOG.md\n"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_repeatedly() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: first commit")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--build")
+        .arg("test.1")
+        .assert()
+        .success();
+
+    run_cmd!(git reset --hard HEAD^)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--build")
+        .arg("test.2")
+        .assert()
+        .success();
+
+    run_cmd!(git reset --hard HEAD^)?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--build")
+        .arg("test.3")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("0.1.0+test.1")?;
+    assert_tag_exists("0.1.0+test.2")?;
+    assert_tag_exists("0.1.0+test.3")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_bug_fix() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: first commit")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: amazing stuff")?;
+    git_commit("feat!: break everything")?;
+    git_tag("2.0.0")?;
+    run_cmd!(
+        git branch bugfix 1.0.0;
+        git switch bugfix;
+    )?;
+    git_commit("fix: important bug fix")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.0.1")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn changelog_on_first_commit_with_tag_on_first_commit() -> Result<()> {
+    git_init()?;
+    git_commit("feat: init")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--disable-bump-commit")
+        .assert()
+        .success();
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("changelog")
+        .arg("--at")
+        .arg("0.1.0")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_from_latest_pre_release() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0-alpha.0")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.0.0")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_prerelease_from_latest_pre_release() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0-alpha.0")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("beta.0")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.0.0-beta.0")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_prerelease_from_latest_pre_release_auto() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0-alpha.1")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("alpha.*")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.0.0-alpha.2")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_prerelease_from_latest_pre_release_auto_2() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("alpha.*")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.1.0-alpha.1")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_prerelease_from_latest_pre_release_auto_3() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: fix 1")?;
+    git_tag("1.0.1-alpha.1")?;
+    git_commit("fix: fix 2")?;
+    git_tag("1.0.1-alpha.2")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("alpha.*")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.1.0-alpha.1")?;
+
+    Ok(())
+}
+#[sealed_test]
+fn bump_from_latest_pre_release_monorepo() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[monorepo.packages.pkg]\npath = \"pkg\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0-alpha.0")?;
+    git_tag("pkg-1.0.0-alpha.0")?;
+    std::fs::create_dir("pkg")?;
+    git_add("fn main() {}", "pkg/main.rs")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.0.0")?;
+    assert_tag_exists("pkg-1.0.0")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_prerelease_from_latest_pre_release_monorepo() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[monorepo.packages.pkg]\npath = \"pkg\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature 1")?;
+    git_tag("1.0.0-alpha.0")?;
+    git_tag("pkg-1.0.0-alpha.0")?;
+    std::fs::create_dir("pkg")?;
+    git_add("fn main() {}", "pkg/main.rs")?;
+    git_commit("feat: feature 2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("beta.0")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.0.0-beta.0")?;
+    assert_tag_exists("pkg-1.0.0-beta.0")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_prerelease_ignore_packages() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {
+            r#"
+            [monorepo.packages.a]
+            path = "a"
+
+            [monorepo.packages.b]
+            path = "b"
+            "#
+        },
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_tag("a-1.0.0")?;
+    git_tag("b-1.0.0")?;
+    git_add(".", "global")?;
+    git_add(".", "a/file")?;
+    git_commit("feat: do stuff")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("rc.1")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.1.0-rc.1")?;
+    assert_tag_exists("a-1.1.0-rc.1")?;
+    assert_tag_does_not_exist("b-1.0.0-rc.1")
+}
+
+#[sealed_test]
+fn major_bump_with_packages() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {
+            r#"
+            [monorepo.packages.a]
+            path = "a"
+
+            [monorepo.packages.b]
+            path = "b"
+            "#
+        },
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("0.1.0")?;
+    git_tag("a-0.1.0")?;
+    git_tag("b-0.1.0")?;
+
+    git_add(".", "a/feat")?;
+    git_add(".", "b/feat")?;
+    git_add(".", "feat")?;
+    git_commit("feat: release 1.0")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--major")
+        .arg("--include-packages")
+        .assert()
+        .success();
+
+    // Assert
+    assert_tag_exists("1.0.0")?;
+    assert_tag_exists("a-1.0.0")?;
+    assert_tag_exists("b-1.0.0")?;
+
+    Ok(())
+}
+
+#[sealed_test]
+fn auto_bump_conflicts_with_include_packages() -> Result<()> {
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--include-packages")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_inserts_release_after_marker() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+    git_add(
+        indoc!(
+            "# Changelog
+
+            Release notes may contain horizontal rules:
+
+            - - -
+
+            <!-- cog:releases -->
+            "
+        ),
+        "CHANGELOG.md",
+    )?;
+    git_commit("chore: add changelog")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).starts_with(
+        "# Changelog\n\nRelease notes may contain horizontal rules:\n\n- - -\n\n<!-- cog:releases -->\n## 0.1.0 - ",
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_migrates_separator_changelog_to_marker_once() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_add("# Changelog\n\n- - -\n", "CHANGELOG.md")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Added insertion marker '<!-- cog:releases -->' to CHANGELOG.md",
+        ));
+
+    git_commit("fix: bug fix")?;
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Added insertion marker").not());
+
+    // Assert
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog.matches("<!-- cog:releases -->").count()).is_equal_to(1);
+    assert_that!(changelog).starts_with("# Changelog\n\n- - -\n<!-- cog:releases -->\n## 0.1.1 - ");
+    assert_that!(changelog).contains("## 0.1.0 - ");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_fails_without_marker_and_fallback() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+    git_add("[changelog]\ninsertion_fallback = \"none\"\n", "cog.toml")?;
+    git_add("# Changelog\n\n- - -\n", "CHANGELOG.md")?;
+    git_commit("chore: add config")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "expected the insertion marker '<!-- cog:releases -->' on its own line",
+        ));
+
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_updates_version_files() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "[package]\nname = \"app\"\nversion = \"0.0.0\"\n",
+        "Cargo.toml",
+    )?;
+    git_add(
+        "{\n  \"name\": \"app\",\n  \"version\": \"0.0.0\"\n}\n",
+        "package.json",
+    )?;
+    git_add(
+        indoc!(
+            r#"
+            [[version_files]]
+            path = "Cargo.toml"
+
+            [[version_files]]
+            path = "package.json"
+            "#
+        ),
+        "cog.toml",
     )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--dry-run")
         .assert()
-        .failure();
+        .success();
 
+    // Assert
+    assert_tag_exists("0.1.0")?;
+    assert_that!(std::fs::read_to_string("Cargo.toml")?)
+        .is_equal_to("[package]\nname = \"app\"\nversion = \"0.1.0\"\n".to_string());
+    assert_that!(std::fs::read_to_string("package.json")?)
+        .is_equal_to("{\n  \"name\": \"app\",\n  \"version\": \"0.1.0\"\n}\n".to_string());
+    // Version files are part of the bump commit
+    let status = cmd_lib::run_fun!(git status --porcelain)?;
+    assert_that!(status).is_empty();
     Ok(())
 }
 
 #[sealed_test]
-fn uncommitted_changes_should_not_throw_error_with_option() -> Result<()> {
-    let mut settings = Settings {
-        skip_untracked: true,
-        ..Default::default()
-    };
+fn bump_from_subdirectory_updates_version_files_at_repository_root() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("0.0.0\n", "VERSION")?;
+    git_add("docs", "docs/README.md")?;
+    git_add("[[version_files]]\npath = \"VERSION\"\n", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
 
-    init_monorepo(&mut settings)?;
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .current_dir("docs")
+        .assert()
+        .success();
 
-    run_cmd!(
-        echo two > two;
-        echo "other changes" > one/file;
+    // Assert
+    assert_tag_exists("0.1.0")?;
+    assert_that!(std::fs::read_to_string("VERSION")?).is_equal_to("0.1.0\n".to_string());
+    assert_that!(Path::new("docs/VERSION").exists()).is_false();
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_dry_run_prints_version_files_diff() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("VERSION=0.0.0\n", "version.env")?;
+    git_add(
+        "[[version_files]]\npath = \"version.env\"\nline = \"VERSION={version}\"\n",
+        "cog.toml",
     )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
         .arg("--dry-run")
+        // Assert
         .assert()
         .success()
-        .stderr(indoc!("Untracked files :\n\tmodified: one/file\n\tnew: two\n\nnothing added to commit but untracked files present (use \"git add\" to track)\n\n"))
-        .stdout(indoc!("one-0.1.0\n0.1.0\n"));
+        .stdout("0.1.0\n")
+        .stderr(predicate::str::contains(
+            "--- a/version.env\n+++ b/version.env\n@@ -1 +1 @@\n-VERSION=0.0.0\n+VERSION=0.1.0\n",
+        ));
 
-    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
-    assert_tag_does_not_exist("1.1.0")?;
+    assert_that!(std::fs::read_to_string("version.env")?)
+        .is_equal_to("VERSION=0.0.0\n".to_string());
     Ok(())
 }
 
 #[sealed_test]
-fn bump_package_with_default_skip_ci_ok() -> Result<()> {
-    let mut settings = Settings {
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
+fn package_bump_updates_package_version_files() -> Result<()> {
+    // Arrange
+    init_monorepo(&mut Settings::default())?;
+    git_add(
+        indoc!(
+            r#"
+            [monorepo.packages.one]
+            path = "one"
+
+            [[monorepo.packages.one.version_files]]
+            path = "package.json"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_add("{ \"version\": \"0.0.0\" }\n", "one/package.json")?;
+    git_commit("chore: add version files")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--skip-ci")
         .arg("--package")
         .arg("one")
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
-
+    // Assert
     assert_tag_exists("one-0.1.0")?;
-    assert!(commit_message.contains("[skip ci]"));
-
+    assert_that!(std::fs::read_to_string("one/package.json")?)
+        .is_equal_to("{ \"version\": \"0.1.0\" }\n".to_string());
     Ok(())
 }
 
 #[sealed_test]
-fn bump_package_with_cog_toml_defined_skip_ci_ok() -> Result<()> {
-    let mut settings = Settings {
-        skip_ci: String::from("[ci-skip]"),
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
+fn calver_auto_bump_starts_a_new_period() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"version_scheme = "calver"
+            calver_format = "YYYY.0M.MICRO"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("2020.01.3")?;
+    git_commit("fix: bug fix")?;
+    let expected = chrono::Local::now().format("%Y.%m.0").to_string();
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--skip-ci")
-        .arg("--package")
-        .arg("one")
+        // Assert
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
+    assert_tag_exists(&expected)?;
+    Ok(())
+}
 
-    assert_tag_exists("one-0.1.0")?;
-    assert!(commit_message.contains("[ci-skip]"));
+#[sealed_test]
+fn calver_auto_bump_increments_micro_in_the_same_period() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(r#"version_scheme = "calver""#, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag(&chrono::Local::now().format("%Y.%m.2").to_string())?;
+    git_commit("feat: feature")?;
+    let expected = chrono::Local::now().format("%Y.%m.3").to_string();
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout(format!("{expected}\n"));
 
     Ok(())
 }
 
 #[sealed_test]
-fn bump_package_with_skip_ci_override_option_takes_precedence() -> Result<()> {
-    let mut settings = Settings {
-        skip_ci: String::from("[ci-skip]"),
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
+fn package_calver_auto_bump() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[monorepo.packages.one]
+            path = "one"
+            version_scheme = "calver"
+            calver_format = "YY.MM.MICRO"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_add("one", "one/file")?;
+    git_commit("chore: init")?;
+    git_tag("one-20.1.0")?;
+    git_tag("1.0.0")?;
+    git_add("two", "one/two")?;
+    git_commit("fix: package fix")?;
+    let expected = chrono::Local::now().format("one-%y.%-m.0").to_string();
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--skip-ci-override")
-        .arg("[ci-skip-override]")
         .arg("--package")
         .arg("one")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout(format!("{expected}\n"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn invalid_calver_format_is_rejected() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"version_scheme = "calver"
+            calver_format = "YYYY.0M"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid calver format 'YYYY.0M'"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn promote_bump_releases_latest_pre_release_with_aggregated_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.3.0")?;
+    git_commit("feat: first feature")?;
+    git_tag("1.4.0-rc.1")?;
+    git_commit("fix: first fix")?;
+    git_tag("1.4.0-rc.2")?;
+    git_commit("feat: second feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--promote")
+        // Assert
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
+    assert_tag_exists("1.4.0")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    let release = changelog
+        .split("## 1.4.0 - ")
+        .nth(1)
+        .and_then(|release| release.split("- - -").next())
+        .unwrap();
+    assert_that!(release).contains("first feature");
+    assert_that!(release).contains("first fix");
+    assert_that!(release).contains("second feature");
+    Ok(())
+}
 
-    assert_tag_exists("one-0.1.0")?;
-    assert!(commit_message.contains("[ci-skip-override]"));
+#[sealed_test]
+fn promote_bump_removes_pre_release_sections() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"[changelog]
+            promoted_pre_releases = "remove"
+            "#
+        ),
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.3.0")?;
+    git_commit("feat: first feature")?;
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .args(["bump", "--minor", "--pre", "rc.*"])
+        .assert()
+        .success();
+    git_commit("fix: first fix")?;
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .args(["bump", "--minor", "--pre", "rc.*"])
+        .assert()
+        .success();
+    assert_tag_exists("1.4.0-rc.2")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--promote")
+        // Assert
+        .assert()
+        .success();
 
+    assert_tag_exists("1.4.0")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).contains("## 1.4.0 - ");
+    assert_that!(changelog).does_not_contain("## 1.4.0-rc.1");
+    assert_that!(changelog).does_not_contain("## 1.4.0-rc.2");
     Ok(())
 }
 
 #[sealed_test]
-fn bump_standard_repository_with_default_skip_ci_ok() -> Result<()> {
+fn promote_bump_dry_run() -> Result<()> {
+    // Arrange
     git_init()?;
     git_commit("chore: init")?;
+    git_tag("1.3.0")?;
     git_commit("feat: feature")?;
+    git_tag("1.4.0-beta.1")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
-        .arg("--skip-ci")
-        .arg("--auto")
+        .arg("--promote")
+        .arg("--dry-run")
+        // Assert
         .assert()
-        .success();
+        .success()
+        .stdout("1.4.0\n");
 
-    let commit_message = git_log_head_message()?;
-    assert_tag_exists("0.1.0")?;
-    assert!(commit_message.contains("[skip ci]"));
+    Ok(())
+}
+
+#[sealed_test]
+fn promote_bump_fails_without_pre_release() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.3.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--promote")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no pre-release greater than 1.3.0 found to promote",
+        ));
 
     Ok(())
 }
 
+const RELEASE_BRANCHES: &str = indoc!(
+    r#"[[release_branches]]
+    name = "main"
+
+    [[release_branches]]
+    name = "next"
+    pre = "beta.*"
+
+    [[release_branches]]
+    name = "alpha/*"
+    pre = "alpha.*"
+    disable_changelog = true
+    "#
+);
+
 #[sealed_test]
-fn bump_standard_repository_with_cog_toml_defined_skip_ci_ok() -> Result<()> {
+fn release_branch_bumps_stable_version() -> Result<()> {
+    // Arrange
     git_init()?;
+    run_cmd!(git checkout -b main;)?;
+    git_add(RELEASE_BRANCHES, "cog.toml")?;
     git_commit("chore: init")?;
-    git_add("skip_ci = \"[ci-skip]\"", "cog.toml")?;
+    git_tag("1.0.0")?;
     git_commit("feat: feature")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
-        .arg("--skip-ci")
         .arg("--auto")
+        // Assert
         .assert()
-        .success();
-
-    let commit_message = git_log_head_message()?;
-    assert_tag_exists("0.1.0")?;
-    assert!(commit_message.contains("[ci-skip]"));
+        .success()
+        .stderr(predicate::str::contains(
+            "Release branch 'main': releasing stable versions",
+        ));
 
+    assert_tag_exists("1.1.0")?;
     Ok(())
 }
 
 #[sealed_test]
-fn bump_standard_repository_skip_ci_override_option_takes_precedence() -> Result<()> {
+fn release_branch_bumps_pre_release_channel() -> Result<()> {
+    // Arrange
     git_init()?;
+    run_cmd!(git checkout -b main;)?;
+    git_add(RELEASE_BRANCHES, "cog.toml")?;
     git_commit("chore: init")?;
-    git_add("skip_ci = \"[ci-skip]\"", "cog.toml")?;
+    git_tag("1.0.0")?;
+    run_cmd!(git checkout -b next;)?;
     git_commit("feat: feature")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
-        .arg("--skip-ci-override")
-        .arg("[ci-skip-override]")
         .arg("--auto")
+        // Assert
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
-    assert_tag_exists("0.1.0")?;
-    assert!(commit_message.contains("[ci-skip-override]"));
-
-    Ok(())
-}
-
-#[sealed_test]
-fn bump_monorepo_with_default_skip_ci_ok() -> Result<()> {
-    let mut settings = Settings {
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
-
+    git_commit("fix: fix")?;
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--skip-ci")
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
-
-    assert_tag_exists("0.1.0")?;
-    assert!(commit_message.contains("[skip ci]"));
-
+    assert_tag_exists("1.1.0-beta.1")?;
+    assert_tag_exists("1.1.0-beta.2")?;
+    assert_that!(Path::new("CHANGELOG.md")).exists();
     Ok(())
 }
 
 #[sealed_test]
-fn bump_monorepo_manual_increment_with_default_skip_ci_ok() -> Result<()> {
-    let mut settings = Settings {
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
+fn release_branch_disables_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(git checkout -b main;)?;
+    git_add(RELEASE_BRANCHES, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    run_cmd!(git checkout -b alpha/parser;)?;
+    git_commit("feat!: new parser")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
-        .arg("--minor")
-        .arg("--skip-ci")
+        .arg("--auto")
+        // Assert
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
-
-    assert_tag_exists("0.1.0")?;
-    assert!(commit_message.contains("[skip ci]"));
-
+    assert_tag_exists("2.0.0-alpha.1")?;
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
     Ok(())
 }
 
 #[sealed_test]
-fn bump_monorepo_with_cog_toml_defined_skip_ci_ok() -> Result<()> {
-    let mut settings = Settings {
-        skip_ci: String::from("[ci-skip]"),
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
+fn bump_is_refused_outside_release_branches() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(git checkout -b main;)?;
+    git_add(RELEASE_BRANCHES, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    run_cmd!(git checkout -b feat/login;)?;
+    git_commit("feat: login")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--skip-ci")
+        // Assert
         .assert()
-        .success();
-
-    let commit_message = git_log_head_message()?;
-
-    assert_tag_exists("0.1.0")?;
-    assert!(commit_message.contains("[ci-skip]"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "No release branch matched in [\"main\", \"next\", \"alpha/*\"] for branch 'feat/login'",
+        ));
 
+    assert_tag_does_not_exist("1.1.0")?;
     Ok(())
 }
 
-#[sealed_test]
-fn bump_monorepo_skip_ci_override_option_takes_precedence() -> Result<()> {
-    let mut settings = Settings {
-        skip_ci: String::from("[ci-skip]"),
-        ..Default::default()
-    };
+const MAINTENANCE_BRANCHES: &str = indoc!(
+    r#"[[release_branches]]
+    name = "main"
 
-    init_monorepo(&mut settings)?;
+    [[release_branches]]
+    name = "release/1.x"
+    range = "1.x"
+    "#
+);
 
+#[sealed_test]
+fn maintenance_branch_bump_ignores_tags_outside_of_range() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(git checkout -b main;)?;
+    git_add(MAINTENANCE_BRANCHES, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.4.1")?;
+    git_commit("fix: main fix")?;
+    git_tag("2.3.0")?;
+    run_cmd!(git checkout -b release/1.x;)?;
+    git_commit("fix: hotfix")?;
+
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--skip-ci-override")
-        .arg("[skip-ci-override]")
+        // Assert
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
-
-    assert_tag_exists("0.1.0")?;
-    assert!(commit_message.contains("[skip-ci-override]"));
-
+    assert_tag_exists("1.4.2")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).contains("## 1.4.2 - ");
+    assert_that!(changelog).contains("hotfix");
     Ok(())
 }
 
 #[sealed_test]
-fn bump_only_package_with_default_skip_ci_ok() -> Result<()> {
-    let mut settings = Settings {
-        generate_mono_repository_global_tag: false,
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
+fn maintenance_branch_changelog_starts_at_latest_tag_in_range() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(git checkout -b main;)?;
+    git_add(MAINTENANCE_BRANCHES, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.4.1")?;
+    run_cmd!(git checkout -b release/1.x;)?;
+    run_cmd!(git checkout main;)?;
+    git_commit("feat: main feature")?;
+    git_tag("2.3.0")?;
+    run_cmd!(git checkout release/1.x;)?;
+    git_commit("fix: hotfix")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--skip-ci")
+        // Assert
         .assert()
         .success();
 
-    let commit_message = git_log_head_message()?;
-
-    assert_tag_does_not_exist("0.1.0")?;
-    assert_tag_exists("one-0.1.0")?;
-    assert!(commit_message.contains("[skip ci]"));
-
+    assert_tag_exists("1.4.2")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).contains("hotfix");
+    assert_that!(changelog).does_not_contain("main feature");
     Ok(())
 }
 
 #[sealed_test]
-fn disable_commit_creation_with_config_standard_ok() -> Result<()> {
+fn maintenance_branch_refuses_bump_outside_of_range() -> Result<()> {
+    // Arrange
     git_init()?;
-
-    git_add("disable_bump_commit = true", "cog.toml")?;
-
+    run_cmd!(git checkout -b main;)?;
+    git_add(MAINTENANCE_BRANCHES, "cog.toml")?;
     git_commit("chore: init")?;
-    git_commit("feat: feature")?;
+    git_tag("1.4.1")?;
+    run_cmd!(git checkout -b release/1.x;)?;
+    git_commit("feat!: breaking hotfix")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
+        // Assert
         .assert()
-        .success();
-
-    assert_tag_exists("0.1.0")?;
-
-    Command::new("git")
-        .arg("status")
-        .arg("-s")
-        .assert()
-        .success()
-        .stdout(indoc!("A  CHANGELOG.md\n"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "2.0.0 is outside of the 1.x maintenance range of this branch",
+        ));
 
+    assert_tag_does_not_exist("2.0.0")?;
     Ok(())
 }
 
 #[sealed_test]
-fn disable_commit_creation_with_flag_standard_ok() -> Result<()> {
+fn failed_post_bump_hook_rolls_back_bump() -> Result<()> {
+    // Arrange
     git_init()?;
-
+    git_add("post_bump_hooks = [ \"exit 1\" ]", "cog.toml")?;
+    git_add("# Changelog\n<!-- cog:releases -->\n", "CHANGELOG.md")?;
     git_commit("chore: init")?;
     git_commit("feat: feature")?;
+    let head = git_log_head_sha()?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--disable-bump-commit")
-        .assert()
-        .success();
-
-    assert_tag_exists("0.1.0")?;
-
-    Command::new("git")
-        .arg("status")
-        .arg("-s")
+        // Assert
         .assert()
-        .success()
-        .stdout(indoc!("A  CHANGELOG.md\n"));
+        .failure()
+        .stderr(predicate::str::contains("The bump has been rolled back"));
 
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(git_log_head_sha()?).is_equal_to(head);
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).is_equal_to("# Changelog\n<!-- cog:releases -->\n".to_string());
     Ok(())
 }
 
 #[sealed_test]
-#[cfg(target_os = "linux")]
-fn disable_commit_creation_with_pre_bump_hooks_standard_ok() -> Result<()> {
+fn failed_post_bump_hook_restores_version_files_and_pre_bump_hook_edits() -> Result<()> {
+    // Arrange
     git_init()?;
-
     git_add(
-        "pre_bump_hooks = [\"echo pre_bump_file > pre_bump_file\"]\ndisable_bump_commit = true",
+        indoc!(
+            r#"pre_bump_hooks = ["echo edited > notes.txt"]
+            post_bump_hooks = ["exit 1"]
+
+            [[version_files]]
+            path = "VERSION"
+            "#
+        ),
         "cog.toml",
     )?;
-
+    git_add("0.0.0\n", "VERSION")?;
+    git_add("notes\n", "notes.txt")?;
     git_commit("chore: init")?;
     git_commit("feat: feature")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--disable-bump-commit")
-        .assert()
-        .success();
-
-    assert_tag_exists("0.1.0")?;
-
-    Command::new("git")
-        .arg("status")
-        .arg("-s")
+        // Assert
         .assert()
-        .success()
-        .stdout(indoc!("A  CHANGELOG.md\nA  pre_bump_file\n"));
+        .failure()
+        .stderr(predicate::str::contains(
+            "restored files: CHANGELOG.md, VERSION, notes.txt",
+        ));
 
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(std::fs::read_to_string("VERSION")?).is_equal_to("0.0.0\n".to_string());
+    assert_that!(std::fs::read_to_string("notes.txt")?).is_equal_to("notes\n".to_string());
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
     Ok(())
 }
 
 #[sealed_test]
-fn override_default_commit() -> Result<()> {
+fn failed_post_bump_hook_from_subdirectory_rolls_back_bump() -> Result<()> {
+    // Arrange
     git_init()?;
-
+    git_add("0.0.0\n", "VERSION")?;
+    git_add("# Changelog\n<!-- cog:releases -->\n", "docs/CHANGELOG.md")?;
     git_add(
-        indoc! {
-          r#"[commit_types]
-          feat = { changelog_title = "🌟 Features" }
-          fix = { changelog_title = "🐛 Bug Fixes" }"#
-        },
+        "post_bump_hooks = [ \"exit 1\" ]\n[[version_files]]\npath = \"VERSION\"\n",
         "cog.toml",
     )?;
-
     git_commit("chore: init")?;
     git_commit("feat: feature")?;
-    git_commit("fix: fix")?;
+    let head = git_log_head_sha()?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
+        .current_dir("docs")
+        // Assert
         .assert()
-        .success();
-
-    assert_tag_exists("0.1.0")?;
+        .failure()
+        .stderr(predicate::str::contains(
+            "restored files: docs/CHANGELOG.md, VERSION",
+        ));
 
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(git_log_head_sha()?).is_equal_to(head);
+    assert_that!(std::fs::read_to_string("docs/CHANGELOG.md")?)
+        .is_equal_to("# Changelog\n<!-- cog:releases -->\n".to_string());
+    assert_that!(std::fs::read_to_string("VERSION")?).is_equal_to("0.0.0\n".to_string());
+    assert_that!(Path::new("docs/docs")).does_not_exist();
     Ok(())
 }
 
 #[sealed_test]
-fn disable_commit_creation_monorepo_ok() -> Result<()> {
-    let mut settings = Settings {
-        disable_bump_commit: true,
-        ..Default::default()
-    };
-    init_monorepo(&mut settings)?;
+fn failed_post_bump_hook_without_rollback_keeps_bump() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("post_bump_hooks = [ \"exit 1\" ]", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
+        .arg("--no-rollback")
+        // Assert
         .assert()
-        .success();
+        .failure();
 
     assert_tag_exists("0.1.0")?;
-    assert_tag_exists("one-0.1.0")?;
-
-    Command::new("git")
-        .arg("status")
-        .arg("-s")
-        .assert()
-        .success()
-        .stdout(indoc!("A  CHANGELOG.md\nA  one/CHANGELOG.md\n"));
-
+    assert_that!(git_log_head_message()?).contains("chore(version): 0.1.0");
     Ok(())
 }
 
 #[sealed_test]
-fn disable_commit_creation_package_ok() -> Result<()> {
+fn failed_post_bump_hook_rolls_back_monorepo_package_tags() -> Result<()> {
+    // Arrange
     let mut settings = Settings {
-        disable_bump_commit: true,
+        post_bump_hooks: vec!["exit 1".to_string()],
         ..Default::default()
     };
     init_monorepo(&mut settings)?;
+    let head = git_log_head_sha()?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--package")
-        .arg("one")
-        .arg("--disable-bump-commit")
-        .assert()
-        .success();
-
-    assert_tag_exists("one-0.1.0")?;
-
-    Command::new("git")
-        .arg("status")
-        .arg("-s")
+        // Assert
         .assert()
-        .success()
-        .stdout(indoc!("A  one/CHANGELOG.md\n"));
+        .failure()
+        .stderr(predicate::str::contains("deleted tags: one-0.1.0, 0.1.0"));
 
+    assert_tag_does_not_exist("one-0.1.0")?;
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(git_log_head_sha()?).is_equal_to(head);
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_that!(Path::new("one/CHANGELOG.md")).does_not_exist();
     Ok(())
 }
 
-#[sealed_test]
-fn bump_repeatedly() -> Result<()> {
-    // Arrange
-    git_init()?;
-    git_commit("feat: first commit")?;
-
-    // Act
-    Command::new(assert_cmd::cargo_bin!("cog"))
-        .arg("bump")
-        .arg("--auto")
-        .arg("--build")
-        .arg("test.1")
-        .assert()
-        .success();
-
-    run_cmd!(git reset --hard HEAD^)?;
+const RELEASE_COMMIT_MESSAGE: &str = indoc!(
+    r#"
+    bump_commit_message = """
+    chore(release): {{ version }}
 
-    Command::new(assert_cmd::cargo_bin!("cog"))
-        .arg("bump")
-        .arg("--auto")
-        .arg("--build")
-        .arg("test.2")
-        .assert()
-        .success();
+    Release-Notes:
+    {% for commit in changelog %}- {{ commit.type }}: {{ commit.summary }}
+    {% endfor %}"""
+    "#
+);
 
-    run_cmd!(git reset --hard HEAD^)?;
+#[sealed_test]
+fn bump_commit_message_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(RELEASE_COMMIT_MESSAGE, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    git_commit("fix: bug fix")?;
 
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--build")
-        .arg("test.3")
+        .arg("--skip-ci")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("0.1.0+test.1")?;
-    assert_tag_exists("0.1.0+test.2")?;
-    assert_tag_exists("0.1.0+test.3")?;
-
+    assert_that!(git_log_head_message()?).is_equal_to(
+        indoc!(
+            "
+            chore(release): 1.1.0 [skip ci]
+
+            Release-Notes:
+            - fix: bug fix
+            - feat: feature"
+        )
+        .to_string(),
+    );
     Ok(())
 }
 
 #[sealed_test]
-fn bump_bug_fix() -> Result<()> {
+fn bump_commit_message_template_lists_pre_release_commits() -> Result<()> {
     // Arrange
     git_init()?;
-    git_commit("feat: first commit")?;
+    git_add(RELEASE_COMMIT_MESSAGE, "cog.toml")?;
+    git_commit("chore: init")?;
     git_tag("1.0.0")?;
-    git_commit("feat: amazing stuff")?;
-    git_commit("feat!: break everything")?;
-    git_tag("2.0.0")?;
-    run_cmd!(
-        git branch bugfix 1.0.0;
-        git switch bugfix;
-    )?;
-    git_commit("fix: important bug fix")?;
+    git_commit("feat: feature")?;
+    git_tag("1.1.0-rc.1")?;
+    git_commit("fix: bug fix")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
+        .arg("--skip-ci")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("1.0.1")?;
-
+    assert_that!(git_log_head_message()?).is_equal_to(
+        indoc!(
+            "
+            chore(release): 1.1.0 [skip ci]
+
+            Release-Notes:
+            - fix: bug fix
+            - feat: feature"
+        )
+        .to_string(),
+    );
     Ok(())
 }
 
 #[sealed_test]
-fn changelog_on_first_commit_with_tag_on_first_commit() -> Result<()> {
-    git_init()?;
-    git_commit("feat: init")?;
+fn monorepo_bump_commit_message_template_lists_packages() -> Result<()> {
+    // Arrange
+    let mut settings = Settings::default();
+    init_monorepo(&mut settings)?;
+    let mut cog_toml = std::fs::read_to_string("cog.toml")?;
+    cog_toml = cog_toml.replace(
+        "[monorepo]",
+        indoc!(
+            r#"
+            [monorepo]
+            bump_commit_message = """
+            chore(release): {{ version }}
 
+            {% for package in packages %}{{ package.name }}: {{ package.version }}
+            {% endfor %}"""
+            "#
+        ),
+    );
+    git_add(&cog_toml, "cog.toml")?;
+    git_commit("chore: configure bump commit")?;
+
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--disable-bump-commit")
-        .assert()
-        .success();
-
-    Command::new(assert_cmd::cargo_bin!("cog"))
-        .arg("changelog")
-        .arg("--at")
-        .arg("0.1.0")
+        // Assert
         .assert()
         .success();
 
+    assert_that!(git_log_head_message()?)
+        .is_equal_to("chore(release): 0.1.0\n\none: 0.1.0".to_string());
     Ok(())
 }
 
 #[sealed_test]
-fn bump_from_latest_pre_release() -> Result<()> {
+fn invalid_bump_commit_message_template_is_rejected() -> Result<()> {
     // Arrange
     git_init()?;
+    git_add("bump_commit_message = \"{{ version\"", "cog.toml")?;
     git_commit("chore: init")?;
-    git_commit("feat: feature 1")?;
-    git_tag("1.0.0-alpha.0")?;
-    git_commit("feat: feature 2")?;
+    git_commit("feat: feature")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
+        // Assert
         .assert()
-        .success();
-
-    // Assert
-    assert_tag_exists("1.0.0")?;
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid bump_commit_message template",
+        ));
 
     Ok(())
 }
 
+#[cfg(not(target_os = "windows"))]
 #[sealed_test]
-fn bump_prerelease_from_latest_pre_release() -> Result<()> {
+fn bump_monorepo_creates_signed_tags() -> Result<()> {
     // Arrange
-    git_init()?;
-    git_commit("chore: init")?;
-    git_commit("feat: feature 1")?;
-    git_tag("1.0.0-alpha.0")?;
-    git_commit("feat: feature 2")?;
+    init_monorepo(&mut Settings::default())?;
+    let assets = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets");
+    let public_key = std::fs::read_to_string(format!("{assets}/sshkey.pub"))?;
+    std::fs::write(
+        ".git/allowed_signers",
+        format!("toml.bombadil@themail.org {public_key}"),
+    )?;
+    run_cmd!(
+        chmod 600 $assets/sshkey;
+        git config --local user.signingkey $assets/sshkey;
+        git config --local tag.gpgSign true;
+        git config --local gpg.format ssh;
+        git config --local gpg.ssh.allowedSignersFile .git/allowed_signers;
+    )?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--pre")
-        .arg("beta.0")
+        .arg("--annotated")
+        .arg("Release {{version}}")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("1.0.0-beta.0")?;
-
+    run_cmd!(
+        git tag -v one-0.1.0;
+        git tag -v 0.1.0;
+    )?;
+    assert_tag_is_annotated("0.1.0")?;
     Ok(())
 }
 
+const GO_MODULE_TAGS: &str = indoc!(
+    r#"
+    tag_format = "{package}/v{version}"
+
+    [monorepo.packages.core]
+    path = "core"
+
+    [monorepo.packages.core-utils]
+    path = "core-utils"
+    "#
+);
+
 #[sealed_test]
-fn bump_prerelease_from_latest_pre_release_auto() -> Result<()> {
+fn bump_with_go_module_tag_format() -> Result<()> {
     // Arrange
     git_init()?;
+    git_add(GO_MODULE_TAGS, "cog.toml")?;
     git_commit("chore: init")?;
-    git_commit("feat: feature 1")?;
-    git_tag("1.0.0-alpha.1")?;
-    git_commit("feat: feature 2")?;
+    git_tag("core/v1.0.0")?;
+    git_tag("core-utils/v2.0.0")?;
+    git_tag("v3.0.0")?;
+    git_add("core", "core/file")?;
+    git_commit("fix: core fix")?;
+    git_add("utils", "core-utils/file")?;
+    git_commit("feat: utils feature")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--pre")
-        .arg("alpha.*")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("1.0.0-alpha.2")?;
-
+    assert_tag_exists("core/v1.0.1")?;
+    assert_tag_exists("core-utils/v2.1.0")?;
+    assert_tag_exists("v3.1.0")?;
     Ok(())
 }
 
 #[sealed_test]
-fn bump_prerelease_from_latest_pre_release_auto_2() -> Result<()> {
+fn bump_package_with_scoped_package_tag_format() -> Result<()> {
     // Arrange
     git_init()?;
+    git_add(
+        indoc!(
+            r#"
+            tag_format = "{package}@{version}"
+
+            [monorepo.packages."@scope/pkg"]
+            path = "pkg"
+            "#
+        ),
+        "cog.toml",
+    )?;
     git_commit("chore: init")?;
-    git_commit("feat: feature 1")?;
-    git_tag("1.0.0")?;
-    git_commit("feat: feature 2")?;
+    git_tag("@scope/pkg@1.0.0")?;
+    git_add("fix", "pkg/file")?;
+    git_commit("fix: pkg fix")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--pre")
-        .arg("alpha.*")
+        .arg("--package")
+        .arg("@scope/pkg")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("1.1.0-alpha.1")?;
-
+    assert_tag_exists("@scope/pkg@1.0.1")?;
     Ok(())
 }
 
 #[sealed_test]
-fn bump_prerelease_from_latest_pre_release_auto_3() -> Result<()> {
+fn tag_format_requires_package_placeholder_in_monorepo() -> Result<()> {
     // Arrange
     git_init()?;
+    git_add(
+        "tag_format = \"v{version}\"\n[monorepo.packages.one]\npath = \"one\"",
+        "cog.toml",
+    )?;
     git_commit("chore: init")?;
-    git_commit("feat: feature 1")?;
-    git_tag("1.0.0")?;
-    git_commit("fix: fix 1")?;
-    git_tag("1.0.1-alpha.1")?;
-    git_commit("fix: fix 2")?;
-    git_tag("1.0.1-alpha.2")?;
-    git_commit("feat: feature 2")?;
+    git_commit("feat: feature")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--pre")
-        .arg("alpha.*")
+        // Assert
         .assert()
-        .success();
-
-    // Assert
-    assert_tag_exists("1.1.0-alpha.1")?;
+        .failure()
+        .stderr(predicate::str::contains(
+            "a {package} placeholder is required in a monorepo",
+        ));
 
     Ok(())
 }
+
 #[sealed_test]
-fn bump_from_latest_pre_release_monorepo() -> Result<()> {
+fn bump_from_legacy_tag() -> Result<()> {
     // Arrange
     git_init()?;
-    git_add("[monorepo.packages.pkg]\npath = \"pkg\"", "cog.toml")?;
+    git_add(
+        indoc!(
+            r#"
+            tag_prefix = "v"
+            legacy_tag_patterns = ['REL_(?P<major>\d+)_(?P<minor>\d+)_(?P<patch>\d+)']
+            "#
+        ),
+        "cog.toml",
+    )?;
     git_commit("chore: init")?;
-    git_commit("feat: feature 1")?;
-    git_tag("1.0.0-alpha.0")?;
-    git_tag("pkg-1.0.0-alpha.0")?;
-    std::fs::create_dir("pkg")?;
-    git_add("fn main() {}", "pkg/main.rs")?;
-    git_commit("feat: feature 2")?;
+    git_tag("REL_2_0_0")?;
+    git_commit("feat: feature")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("1.0.0")?;
-    assert_tag_exists("pkg-1.0.0")?;
-
+    assert_tag_exists("v2.1.0")?;
+    assert_tag_does_not_exist("REL_2_1_0")?;
     Ok(())
 }
 
 #[sealed_test]
-fn bump_prerelease_from_latest_pre_release_monorepo() -> Result<()> {
+fn invalid_legacy_tag_pattern_is_rejected() -> Result<()> {
     // Arrange
     git_init()?;
-    git_add("[monorepo.packages.pkg]\npath = \"pkg\"", "cog.toml")?;
+    git_add(r"legacy_tag_patterns = ['release-(\d+)']", "cog.toml")?;
     git_commit("chore: init")?;
-    git_commit("feat: feature 1")?;
-    git_tag("1.0.0-alpha.0")?;
-    git_tag("pkg-1.0.0-alpha.0")?;
-    std::fs::create_dir("pkg")?;
-    git_add("fn main() {}", "pkg/main.rs")?;
-    git_commit("feat: feature 2")?;
+    git_commit("feat: feature")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--pre")
-        .arg("beta.0")
+        // Assert
         .assert()
-        .success();
-
-    // Assert
-    assert_tag_exists("1.0.0-beta.0")?;
-    assert_tag_exists("pkg-1.0.0-beta.0")?;
+        .failure()
+        .stderr(predicate::str::contains(
+            "a `major` capture group is required",
+        ));
 
     Ok(())
 }
 
 #[sealed_test]
-fn bump_prerelease_ignore_packages() -> Result<()> {
+fn bump_from_version_source() -> Result<()> {
     // Arrange
     git_init()?;
     git_add(
-        indoc! {
+        indoc!(
             r#"
-            [monorepo.packages.a]
-            path = "a"
+            [version_source]
+            path = "Cargo.toml"
 
-            [monorepo.packages.b]
-            path = "b"
+            [[version_files]]
+            path = "Cargo.toml"
             "#
-        },
+        ),
         "cog.toml",
     )?;
+    git_add(
+        "[package]\nname = \"cog\"\nversion = \"1.0.0\"\n",
+        "Cargo.toml",
+    )?;
     git_commit("chore: init")?;
-    git_tag("1.0.0")?;
-    git_tag("a-1.0.0")?;
-    git_tag("b-1.0.0")?;
-    git_add(".", "global")?;
-    git_add(".", "a/file")?;
-    git_commit("feat: do stuff")?;
+    git_commit("feat: released feature")?;
+    git_add(
+        "[package]\nname = \"cog\"\nversion = \"1.1.0\"\n",
+        "Cargo.toml",
+    )?;
+    git_commit("chore(version): 1.1.0")?;
+    git_commit("fix: bug fix")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--pre")
-        .arg("rc.1")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("1.1.0-rc.1")?;
-    assert_tag_exists("a-1.1.0-rc.1")?;
-    assert_tag_does_not_exist("b-1.0.0-rc.1")
+    assert_tag_exists("1.1.1")?;
+    assert_that!(std::fs::read_to_string("Cargo.toml")?).contains("version = \"1.1.1\"");
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    assert_that!(changelog).contains("bug fix");
+    assert_that!(changelog).does_not_contain("released feature");
+    Ok(())
 }
 
 #[sealed_test]
-fn major_bump_with_packages() -> Result<()> {
+fn bump_from_version_source_in_subdirectory() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[version_source]\npath = \"VERSION\"\n", "cog.toml")?;
+    git_add("1.0.0\n", "VERSION")?;
+    git_add("docs", "docs/README.md")?;
+    git_commit("chore: init")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("bump")
+        .arg("--auto")
+        .current_dir("docs")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_package_from_version_source() -> Result<()> {
     // Arrange
     git_init()?;
     git_add(
-        indoc! {
+        indoc!(
             r#"
-            [monorepo.packages.a]
-            path = "a"
+            [monorepo.packages.one]
+            path = "one"
 
-            [monorepo.packages.b]
-            path = "b"
+            [monorepo.packages.one.version_source]
+            path = "package.json"
             "#
-        },
+        ),
         "cog.toml",
     )?;
+    git_add(
+        r#"{ "name": "one", "version": "0.3.0" }"#,
+        "one/package.json",
+    )?;
     git_commit("chore: init")?;
-    git_tag("0.1.0")?;
-    git_tag("a-0.1.0")?;
-    git_tag("b-0.1.0")?;
-
-    git_add(".", "a/feat")?;
-    git_add(".", "b/feat")?;
-    git_add(".", "feat")?;
-    git_commit("feat: release 1.0")?;
+    git_add("feature", "one/file")?;
+    git_commit("feat: package feature")?;
 
     // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
-        .arg("--major")
-        .arg("--include-packages")
+        .arg("--auto")
+        .arg("--package")
+        .arg("one")
+        // Assert
         .assert()
         .success();
 
-    // Assert
-    assert_tag_exists("1.0.0")?;
-    assert_tag_exists("a-1.0.0")?;
-    assert_tag_exists("b-1.0.0")?;
-
+    assert_tag_exists("one-0.4.0")?;
     Ok(())
 }
 
 #[sealed_test]
-fn auto_bump_conflicts_with_include_packages() -> Result<()> {
+fn bump_fails_when_version_source_is_missing() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("[version_source]\npath = \"VERSION\"", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
     Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("bump")
         .arg("--auto")
-        .arg("--include-packages")
+        // Assert
         .assert()
-        .failure();
+        .failure()
+        .stderr(predicate::str::contains(
+            "version source VERSION not found at HEAD",
+        ));
 
     Ok(())
 }
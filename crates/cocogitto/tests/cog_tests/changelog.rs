@@ -655,6 +655,174 @@ fn should_get_global_changelog() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn should_render_issue_links_with_remote_template() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let cog = indoc!(
+        r#"[changelog]
+        template = "remote"
+        remote = "github.com"
+        repository = "test"
+        owner = "test"
+
+        [issues]
+        patterns = ["(?<id>COG-[0-9]+)"]
+        url = "https://jira.example.com/browse/{id}"
+        "#
+    );
+    git_add(cog, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: add login\n\nRefs: COG-42")?;
+
+    // Act
+    let changelog = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = String::from_utf8_lossy(&changelog.get_output().stdout).to_string();
+    assert!(
+        changelog.contains("add login ([COG-42](https://jira.example.com/browse/COG-42)) - (["),
+        "Expected issue link in changelog but got:\n\n{changelog}"
+    );
+
+    Ok(())
+}
+
+#[sealed_test]
+fn should_export_releases_as_json() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: add login")?;
+    git_tag("1.0.0")?;
+    git_commit("fix(api): crash\n\nCo-authored-by: Jane <jane@example.com>")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("changelog")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success();
+
+    let export: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)?;
+    assert_eq!(export["schema_version"], 1);
+
+    let releases = export["releases"].as_array().unwrap();
+    assert_eq!(releases.len(), 2);
+    assert_eq!(releases[0]["bump"], "patch");
+    assert_eq!(releases[0]["from"]["tag"], "1.0.0");
+    assert_eq!(releases[0]["commits"][0]["type"], "fix");
+    assert_eq!(releases[0]["commits"][0]["scope"], "api");
+    assert_eq!(
+        releases[0]["commits"][0]["footers"][0]["token"],
+        "Co-authored-by"
+    );
+    assert_eq!(releases[0]["commits"][0]["author"]["signature"], "Tom");
+    assert_eq!(releases[1]["version"]["tag"], "1.0.0");
+    assert_eq!(releases[1]["bump"], "minor");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn should_export_releases_as_yaml() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: add login")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("changelog")
+        .arg("--format")
+        .arg("yaml")
+        // Assert
+        .assert()
+        .success();
+
+    let export: serde_yaml::Value = serde_yaml::from_slice(&output.get_output().stdout)?;
+    assert_eq!(export["schema_version"], 1);
+    assert_eq!(export["releases"][0]["commits"][0]["summary"], "add login");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn should_export_release_at_tag() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: add login")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: crash")?;
+    git_tag("1.0.1")?;
+    git_commit("fix: typo")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("changelog")
+        .arg("--at")
+        .arg("1.0.1")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success();
+
+    let export: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)?;
+    let releases = export["releases"].as_array().unwrap();
+    assert_eq!(releases.len(), 1);
+    assert_eq!(releases[0]["version"]["tag"], "1.0.1");
+    assert_eq!(releases[0]["commits"][0]["summary"], "crash");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn should_export_monorepo_global_releases() -> Result<()> {
+    // Arrange
+    init_monorepo(&mut Settings::default())?;
+    git_add("docs", "README.md")?;
+    git_commit("docs: global docs")?;
+
+    let export = |unified: bool| -> Result<serde_json::Value> {
+        let mut command = Command::new(assert_cmd::cargo_bin!("cog"));
+        command.arg("changelog").arg("--format").arg("json");
+        if unified {
+            command.arg("--unified");
+        }
+
+        let output = command.assert().success();
+        Ok(serde_json::from_slice(&output.get_output().stdout)?)
+    };
+
+    // Act
+    let global = export(false)?;
+    let unified = export(true)?;
+
+    // Assert
+    let summaries = |export: &serde_json::Value| -> Vec<String> {
+        export["releases"][0]["commits"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|commit| commit["summary"].as_str().unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(summaries(&global), vec!["global docs", "first commit"]);
+    assert_eq!(
+        summaries(&unified),
+        vec!["global docs", "package one feature", "first commit"]
+    );
+
+    Ok(())
+}
+
 /// Test that the `order` configuration
 /// directive is honored if/when it is specified for
 /// a given commit type and used in a sort.
@@ -896,7 +1064,7 @@ fn changelog_from_commit_range_should_be_the_same_as_changelog_from_tag_range()
     // Act
     let changelog_from_commit_range = Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("changelog")
-        .arg(&format!("{sha_0_1}..{sha_0_2}"))
+        .arg(format!("{sha_0_1}..{sha_0_2}"))
         .assert()
         .success();
 
@@ -905,7 +1073,7 @@ fn changelog_from_commit_range_should_be_the_same_as_changelog_from_tag_range()
 
     let changelog_from_tag_range = Command::new(assert_cmd::cargo_bin!("cog"))
         .arg("changelog")
-        .arg(&"0.1.0..0.2.0".to_string())
+        .arg("0.1.0..0.2.0")
         .assert()
         .success();
 
@@ -1158,3 +1326,112 @@ fn monorepo_changelog_default_template() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn get_changelog_with_legacy_tags() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc!(
+            r#"
+            legacy_tag_patterns = [
+                'release-(?P<major>\d+)\.(?P<minor>\d+)',
+                'REL_(?P<major>\d+)_(?P<minor>\d+)_(?P<patch>\d+)',
+            ]
+            "#
+        ),
+        "cog.toml",
+    )?;
+    let init = git_commit("chore: init")?;
+    let commit_one = git_commit("feat: feature 1")?;
+    git_tag("release-1.2")?;
+    let commit_two = git_commit("feat: feature 2")?;
+    git_tag("REL_2_0_0")?;
+    let commit_three = git_commit("fix: bug fix 1")?;
+
+    // Act
+    let changelog = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("changelog")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = &changelog.stdout;
+    let changelog = String::from_utf8_lossy(changelog.as_slice());
+    let today = Utc::now().date_naive();
+
+    assert_doc_eq!(
+        changelog.as_ref(),
+        "## Unreleased ({commit_three}..{commit_three})
+        #### Bug Fixes
+        - bug fix 1 - ({commit_three}) - Tom
+
+        - - -
+
+        ## REL_2_0_0 - {today}
+        #### Features
+        - feature 2 - ({commit_two}) - Tom
+
+        - - -
+
+        ## release-1.2 - {today}
+        #### Features
+        - feature 1 - ({commit_one}) - Tom
+        #### Miscellaneous Chores
+        - init - ({init}) - Tom
+
+
+        ",
+        today = today,
+        init = &init[0..7],
+        commit_one = &commit_one[0..7],
+        commit_two = &commit_two[0..7],
+        commit_three = &commit_three[0..7]
+    );
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_changelog_at_legacy_tag() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        r"legacy_tag_patterns = ['release-(?P<major>\d+)\.(?P<minor>\d+)']",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("release-1.2")?;
+    let commit_one = git_commit("feat: feature 1")?;
+    git_tag("release-1.3")?;
+    git_commit("fix: bug fix 1")?;
+
+    // Act
+    let changelog = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("changelog")
+        .arg("--at")
+        .arg("release-1.3")
+        // Assert
+        .assert()
+        .success();
+
+    let changelog = changelog.get_output();
+    let changelog = &changelog.stdout;
+    let changelog = String::from_utf8_lossy(changelog.as_slice());
+    let today = Utc::now().date_naive();
+
+    assert_doc_eq!(
+        changelog.as_ref(),
+        "## release-1.3 - {today}
+        #### Features
+        - feature 1 - ({commit_one}) - Tom
+
+
+        ",
+        today = today,
+        commit_one = &commit_one[0..7]
+    );
+
+    Ok(())
+}
@@ -196,3 +196,144 @@ fn cog_invalid_commit_scopes() -> Result<()> {
         .failure();
     Ok(())
 }
+
+#[sealed_test]
+fn cog_check_json_format_failure() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let errored = git_commit("toto: errored commit")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["error_count"], 1);
+    assert_eq!(report["errors"][0]["oid"], errored.as_str());
+    assert_eq!(report["errors"][0]["kind"], "commit_type_not_allowed");
+    assert_eq!(report["errors"][0]["commit_type"], "toto");
+    assert_eq!(report["errors"][0]["author"], "Tom");
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_json_format_reports_parse_error_span() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("not conventional")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["errors"][0]["kind"], "commit_format");
+    assert_eq!(report["errors"][0]["span"]["start"]["line"], 1);
+    assert_eq!(report["errors"][0]["span"]["start"]["column"], 4);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_json_format_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["error_count"], 0);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_sarif_format_failure() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let errored = git_commit("toto: errored commit")?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check")
+        .arg("--format")
+        .arg("sarif")
+        // Assert
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    let result = &report["runs"][0]["results"][0];
+    assert_eq!(report["version"], "2.1.0");
+    assert_eq!(result["ruleId"], "commit-type-not-allowed");
+    assert_eq!(
+        result["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+        errored.as_str()
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_lint_error_failure() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: WIP feature")?;
+    std::fs::write(
+        "cog.toml",
+        r#"lint.forbidden_words = { level = "error", words = ["wip"] }"#,
+    )?;
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["errors"][0]["kind"], "lint_error");
+    assert_eq!(
+        report["errors"][0]["violations"][0]["rule"],
+        "forbidden-words"
+    );
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::fs;
+use std::process::Command;
+
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use cmd_lib::run_cmd;
+use predicates::str::contains;
+use sealed_test::prelude::*;
+
+#[sealed_test]
+fn check_branch_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    fs::write(
+        "cog.toml",
+        r#"branch_patterns = ["<type>/<ticket>-<slug>"]"#,
+    )?;
+    run_cmd!(git checkout -b feat/COG-42-add-login;)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check-branch")
+        // Assert
+        .assert()
+        .success()
+        .stderr(contains("type: feat, ticket: COG-42, slug: add-login"));
+    Ok(())
+}
+
+#[sealed_test]
+fn check_branch_with_unknown_type_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    fs::write(
+        "cog.toml",
+        r#"branch_patterns = ["main", "<type>/<ticket>-<slug>"]"#,
+    )?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check-branch")
+        .arg("wip/COG-42-add-login")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(contains("does not match any of the configured patterns"));
+    Ok(())
+}
+
+#[sealed_test]
+fn check_branch_without_patterns_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check-branch")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(contains("No `branch_patterns` configured"));
+    Ok(())
+}
+
+#[sealed_test]
+fn check_branch_with_invalid_pattern_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    fs::write("cog.toml", r#"branch_patterns = ["<kind>/<slug>"]"#)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("check-branch")
+        .arg("feat/add-login")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(contains("unknown placeholder '<kind>'"));
+    Ok(())
+}
@@ -28,6 +28,91 @@ fn commit_ok() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn commit_without_type_outside_terminal_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("commit")
+        .stdin(std::process::Stdio::null())
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("interactive wizard"));
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_with_type_only_outside_terminal_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("commit")
+        .arg("feat")
+        .stdin(std::process::Stdio::null())
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Missing commit message"));
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_fails_on_lint_error() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+    fs::write(
+        "cog.toml",
+        "[lint]\nsummary_trailing_period = { level = \"error\" }",
+    )?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message.")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "[summary-trailing-period] summary must not end with a period",
+        ));
+
+    assert!(git_log_head_message().is_err());
+    Ok(())
+}
+
+#[sealed_test]
+fn commit_fails_with_scope_not_allowed() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("content", "test_file")?;
+    fs::write("cog.toml", r#"scopes = ["core"]"#)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("commit")
+        .arg("feat")
+        .arg("this is a commit message")
+        .arg("other")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Commit scope `other` not allowed",
+        ));
+
+    assert!(git_log_head_message().is_err());
+    Ok(())
+}
+
 #[sealed_test]
 fn commit_fail_if_not_a_repository() -> Result<()> {
     // Act
@@ -1,8 +1,10 @@
 mod bump;
 mod changelog;
 mod check;
+mod check_branch;
 mod commit;
 mod get_version;
 mod init;
+mod prepare_message;
 mod resolver_integration_test;
 mod verify;
@@ -0,0 +1,135 @@
+use std::fs;
+use std::process::Command;
+
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use cmd_lib::run_cmd;
+use cocogitto::settings::Settings;
+use pretty_assertions::assert_eq;
+use sealed_test::prelude::*;
+
+#[sealed_test]
+fn prepare_message_infers_type_and_scope_from_branch() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    run_cmd!(git checkout -b feat/api-login;)?;
+    fs::write(
+        "COMMIT_MSG",
+        "\n# Please enter the commit message for your changes.\n",
+    )?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("prepare-message")
+        .arg("COMMIT_MSG")
+        // Assert
+        .assert()
+        .success();
+
+    let message = fs::read_to_string("COMMIT_MSG")?;
+    assert_eq!(message.lines().next(), Some("feat(api): "));
+    assert!(message.contains("# Type inferred from branch 'feat/api-login'"));
+    assert!(message.ends_with("# Please enter the commit message for your changes.\n"));
+    Ok(())
+}
+
+#[sealed_test]
+fn prepare_message_infers_scope_from_staged_package() -> Result<()> {
+    // Arrange
+    init_monorepo(&mut Settings::default())?;
+    run_cmd!(
+        git checkout -b fix/crash-on-start;
+        echo "changes" > one/other_file;
+        git add one/other_file;
+    )?;
+    fs::write("COMMIT_MSG", "")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("prepare-message")
+        .arg("COMMIT_MSG")
+        // Assert
+        .assert()
+        .success();
+
+    let message = fs::read_to_string("COMMIT_MSG")?;
+    assert_eq!(message.lines().next(), Some("fix(one): "));
+    assert!(message.contains("# Staged changes touch packages: one"));
+    Ok(())
+}
+
+#[sealed_test]
+fn prepare_message_keeps_message_from_command_line() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    run_cmd!(git checkout -b feat/api-login;)?;
+    fs::write("COMMIT_MSG", "fix: a message\n")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("prepare-message")
+        .arg("COMMIT_MSG")
+        .arg("message")
+        // Assert
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string("COMMIT_MSG")?, "fix: a message\n");
+    Ok(())
+}
+
+#[sealed_test]
+fn prepare_message_uses_branch_patterns() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    fs::write(
+        "cog.toml",
+        r#"branch_patterns = ["<type>/<scope>/<ticket>-<slug>"]"#,
+    )?;
+    run_cmd!(git checkout -b fix/parser/COG-7-crash;)?;
+    fs::write("COMMIT_MSG", "")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("prepare-message")
+        .arg("COMMIT_MSG")
+        // Assert
+        .assert()
+        .success();
+
+    let message = fs::read_to_string("COMMIT_MSG")?;
+    assert_eq!(message.lines().next(), Some("fix(parser): "));
+    assert!(message.contains("# Ticket: COG-7"));
+    Ok(())
+}
+
+#[sealed_test]
+fn prepare_message_ignores_branch_convention_when_patterns_do_not_match() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    fs::write(
+        "cog.toml",
+        r#"branch_patterns = ["<ticket>/<type>-<slug>"]"#,
+    )?;
+    run_cmd!(git checkout -b feat/api-login;)?;
+    fs::write("COMMIT_MSG", "")?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("prepare-message")
+        .arg("COMMIT_MSG")
+        // Assert
+        .assert()
+        .success();
+
+    let message = fs::read_to_string("COMMIT_MSG")?;
+    assert_eq!(message.lines().next(), Some(""));
+    assert!(!message.contains("# Type inferred from branch"));
+    Ok(())
+}
@@ -111,6 +111,48 @@ fn verify_with_invalid_scopes_setting_fails() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn verify_with_scope_pattern_setting_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = r#"scopes = ["api-*"]"#;
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("verify")
+        .arg("feat(api-login): a commit message")
+        // Assert
+        .assert()
+        .success();
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_commit_type_scopes_setting_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"
+        scopes = ["core"]
+
+        [commit_types]
+        ci = { scopes = ["github", "gitlab"] }
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("verify")
+        .arg("ci(core): a commit message")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Commit scope `core` not allowed"));
+    Ok(())
+}
+
 #[sealed_test]
 fn verify_with_empty_scopes_setting_fails() -> Result<()> {
     // Arrange
@@ -132,6 +174,88 @@ fn verify_with_empty_scopes_setting_fails() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn verify_with_lint_error_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r#"
+        [lint]
+        header_max_length = { max = 20 }
+
+        [[lint.required_footers]]
+        token = "Refs"
+        types = ["fix"]
+        "#
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("verify")
+        .arg("fix: a commit message that is too long")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "[header-max-length] header is 38 characters long, maximum is 20",
+        ))
+        .stderr(predicates::str::contains(
+            "[required-footer] footer `Refs` is required for `fix` commits",
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_missing_issue_reference_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = indoc!(
+        r##"
+        [issues]
+        patterns = ["#([0-9]+)"]
+        required = ["fix"]
+        "##
+    );
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("verify")
+        .arg("fix: a bug")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("requires an issue reference"));
+
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("verify")
+        .arg("fix: a bug\n\nCloses #12")
+        .assert()
+        .success();
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_with_lint_warning_succeeds() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let settings = r#"lint.summary_case = { level = "warning", case = "lower" }"#;
+    std::fs::write("cog.toml", settings)?;
+
+    // Act
+    Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("verify")
+        .arg("feat: Add a feature")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "[summary-case] summary must start with a lowercase letter",
+        ));
+    Ok(())
+}
+
 #[test]
 fn verify_fails() -> Result<()> {
     // Arrange
@@ -148,6 +272,35 @@ fn verify_fails() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn verify_fails_with_json_format() -> Result<()> {
+    // Arrange
+    let message = "invalid message";
+
+    // Act
+    let output = Command::new(assert_cmd::cargo_bin!("cog"))
+        .arg("verify")
+        .arg("--format")
+        .arg("json")
+        .arg(message)
+        // Assert
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["error_count"], 1);
+    assert_eq!(report["errors"][0]["kind"], "parse_error");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Missing commit type separator `:`"
+    );
+    assert_eq!(report["errors"][0]["span"]["start"]["column"], 8);
+    Ok(())
+}
+
 #[test]
 fn verify_with_unknown_commit_type_fails() -> Result<()> {
     // Arrange
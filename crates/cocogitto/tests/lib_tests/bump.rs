@@ -9,7 +9,7 @@ use sealed_test::prelude::*;
 use speculoos::prelude::*;
 
 use crate::helpers::*;
-use cocogitto::command::bump::{BumpOptions, PackageBumpOptions};
+use cocogitto::command::bump::{BumpOptions, BumpPlanFormat, PackageBumpOptions};
 use cocogitto::settings::{MonoRepoPackage, MonorepoConfig, Settings};
 use cocogitto::{
     conventional::version::{IncrementCommand, PreCommand},
@@ -362,7 +362,10 @@ fn ordered_package_bump() -> Result<()> {
         skip_ci_override: None,
         skip_untracked: false,
         disable_bump_commit: false,
+        no_rollback: false,
         include_packages: false,
+        format: BumpPlanFormat::Version,
+        explain: false,
     })?;
 
     // Assert